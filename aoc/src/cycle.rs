@@ -0,0 +1,151 @@
+//! Detecting when repeatedly applying a step function starts repeating itself, so that a state a
+//! huge number of steps away can be found without taking every step.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The shape of a sequence of states that eventually repeats: after `start` steps the states
+/// repeat every `period` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,  // steps taken before the first repeating state
+    pub period: usize, // length of the repeating part
+}
+
+impl Cycle {
+    /// The earliest step whose state is the same as the one after `n` steps.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+
+    /// Works out the state after `n` steps by taking only the steps needed to reach an
+    /// equivalent state.
+    pub fn fast_forward<S>(&self, mut state: S, mut next: impl FnMut(&S) -> S, n: usize) -> S {
+        for _ in 0..self.equivalent_step(n) {
+            state = next(&state);
+        }
+
+        state
+    }
+}
+
+/// Every state up to the point the sequence starts repeating, along with the cycle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct History<S> {
+    pub states: Vec<S>, // the state after each number of steps, until the first repeat
+    pub cycle: Cycle,
+}
+
+impl<S> History<S> {
+    /// The state after `n` steps.
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.equivalent_step(n)]
+    }
+}
+
+/// Finds the cycle by remembering every state seen, which is quick but keeps all of them around.
+pub fn detect<S: Clone + Eq + Hash>(start: S, mut next: impl FnMut(&S) -> S) -> History<S> {
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+
+    loop {
+        let state = next(states.last().unwrap());
+
+        if let Some(&first_seen) = seen.get(&state) {
+            let cycle = Cycle {
+                start: first_seen,
+                period: states.len() - first_seen,
+            };
+
+            return History { states, cycle };
+        }
+
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only keeps a couple of states at a time. This is
+/// slower than [`detect`] as the steps are taken several times over, but suits states that are
+/// large or can't be hashed.
+///
+/// https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+pub fn brent<S: Clone + Eq>(start: S, mut next: impl FnMut(&S) -> S) -> Cycle {
+    // Find the period by moving the hare ahead in ever larger powers of two until it lands on the
+    // tortoise, which waits at the start of each power.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+
+        hare = next(&hare);
+        period += 1;
+    }
+
+    // With the hare a period ahead of the tortoise, they first meet where the cycle starts.
+    let mut tortoise = start.clone();
+    let mut hare = start;
+
+    for _ in 0..period {
+        hare = next(&hare);
+    }
+
+    let mut cycle_start = 0;
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        cycle_start += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        period,
+    }
+}
+
+/// The state after `n` steps, skipping ahead once the states start repeating. See [`detect`],
+/// which this walks the states with until the first repeat, whatever `n` is.
+pub fn nth<S: Clone + Eq + Hash>(start: S, next: impl FnMut(&S) -> S, n: usize) -> S {
+    detect(start, next).nth(n).clone()
+}
+
+#[test]
+fn cycles() {
+    // 3 → 10 → 5 → 16 → 8 → 4 → 2 → 1 → 4 → 2 → 1 …
+    let collatz = |&n: &u64| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+    let expected = Cycle {
+        start: 5,
+        period: 3,
+    };
+
+    let history = detect(3, collatz);
+    assert_eq!(history.cycle, expected);
+    assert_eq!(history.states, [3, 10, 5, 16, 8, 4, 2, 1]);
+    assert_eq!(*history.nth(4), 8);
+    assert_eq!(*history.nth(1_000_000), 1);
+
+    assert_eq!(brent(3, collatz), expected);
+    assert_eq!(
+        brent(1, collatz),
+        Cycle {
+            start: 0,
+            period: 3
+        }
+    );
+    assert_eq!(expected.fast_forward(3, collatz, 1_000_000), 1);
+
+    assert_eq!(nth(3, collatz, 2), 5);
+    assert_eq!(nth(3, collatz, 1_000_001), 4);
+}
@@ -0,0 +1,168 @@
+//! Parse errors that point at the offending part of the puzzle input.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Debug)]
+pub struct ParseError {
+    message: String,
+    span: (usize, usize), // address, length
+    day: Option<u8>,
+    location: Option<Location>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Location {
+    pub line: usize,   // 1-based
+    pub column: usize, // 1-based, counted in characters
+    pub snippet: String,
+    pub width: usize, // characters to underline
+}
+
+impl ParseError {
+    /// Creates an error about `span`, which must be a slice of the puzzle input so that the error
+    /// can later be located within it. See [`ParseError::locate`].
+    pub fn new(span: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            span: (span.as_ptr() as usize, span.len()),
+            day: None,
+            location: None,
+        }
+    }
+
+    /// Works out the line and column of the error now that the full input is available.
+    ///
+    /// Parsers only hold on to slices of the input, so rather than threading the input through
+    /// every helper, the span's address is compared against where the input lives.
+    pub fn locate(mut self, input: &str) -> ParseError {
+        let (address, len) = self.span;
+        let input_address = input.as_ptr() as usize;
+
+        if address < input_address || address + len > input_address + input.len() {
+            return self;
+        }
+
+        let offset = address - input_address;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        let span_end = (offset + len).min(line_end);
+
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end].trim_end().to_string(),
+            width: input[offset..span_end].chars().count().max(1),
+        });
+
+        self
+    }
+
+    /// Moves an error located within a single line to that line of the full input, for when the
+    /// input is read a line at a time.
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        if let Some(location) = &mut self.location {
+            location.line = line;
+        }
+
+        self
+    }
+
+    pub fn with_day(mut self, day: u8) -> ParseError {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut context = Vec::new();
+
+        if let Some(day) = self.day {
+            context.push(format!("day {day}"));
+        }
+
+        if let Some(location) = &self.location {
+            context.push(format!(
+                "line {}, column {}",
+                location.line, location.column
+            ));
+        }
+
+        if !context.is_empty() {
+            write!(f, "{}: ", context.join(", "))?;
+        }
+
+        write!(f, "{}", self.message)?;
+
+        // Underline the offending text, compiler style:
+        //
+        //   |
+        // 3 | Game 3: 8 purple, 6 blue
+        //   |           ^^^^^^
+        if let Some(location) = &self.location {
+            let gutter = " ".repeat(location.line.to_string().len());
+            writeln!(f)?;
+            writeln!(f, "{gutter} |")?;
+            let line = format!("{} | {}", location.line, location.snippet);
+            writeln!(f, "{}", line.trim_end())?;
+
+            write!(
+                f,
+                "{gutter} | {}{}",
+                " ".repeat(location.column - 1),
+                "^".repeat(location.width)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+#[test]
+fn diagnostic() {
+    let input = "Game 1: 3 blue\nGame 2: 8 purple, 6 blue\n";
+    let start = input.find("purple").unwrap();
+    let span = &input[start..start + 6];
+    let error = ParseError::new(span, "unknown colour `purple`").locate(input);
+
+    assert_eq!(
+        error.location(),
+        Some(&Location {
+            line: 2,
+            column: 11,
+            snippet: "Game 2: 8 purple, 6 blue".to_string(),
+            width: 6,
+        })
+    );
+
+    assert_eq!(
+        error.with_day(2).to_string(),
+        "day 2, line 2, column 11: unknown colour `purple`
+  |
+2 | Game 2: 8 purple, 6 blue
+  |           ^^^^^^"
+    );
+
+    let error = ParseError::new("elsewhere", "not from the input").locate(input);
+    assert_eq!(error.location(), None);
+    assert_eq!(error.to_string(), "not from the input");
+}
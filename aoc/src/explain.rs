@@ -0,0 +1,100 @@
+//! Notes a solution can leave on how it got to its answer, like the value each line of the input adds
+//! up to, for tracking down where a wrong answer went wrong without sprinkling in `println!`s.
+//!
+//! Noting is off unless [`enable`]d, and while it's off [`explain!`](crate::explain!) only checks a
+//! flag: the values it would note aren't even worked out. The notes are kept per thread, and
+//! [`solution::run`](crate::solution::run) takes them after each part so they end up in its report.
+
+use std::cell::RefCell;
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static NOTES: RefCell<Vec<Note>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Notes the given facts, like `explain!(line = line, value = first * 10 + last)`, if noting is
+/// enabled.
+#[macro_export]
+macro_rules! explain {
+    ($($name:ident = $value:expr),+ $(,)?) => {
+        if $crate::explain::is_enabled() {
+            $crate::explain::note($crate::explain::Note {
+                facts: vec![$((stringify!($name), ::std::string::ToString::to_string(&$value))),+],
+            });
+        }
+    };
+}
+
+/// A step on the way to an answer, as the facts worked out in it, in order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Note {
+    pub facts: Vec<(&'static str, String)>,
+}
+
+impl Display for Note {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, (name, value)) in self.facts.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{separator}{} {value}", name.replace('_', " "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Turns noting on, for every thread, for the rest of the run.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+#[inline]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Adds a note to this thread's. Use [`explain!`](crate::explain!) rather than calling this
+/// directly, so nothing is worked out when noting is off.
+pub fn note(note: Note) {
+    NOTES.with(|notes| notes.borrow_mut().push(note));
+}
+
+/// Takes the notes left on this thread so far.
+pub fn take() -> Vec<Note> {
+    if !is_enabled() {
+        return Vec::new();
+    }
+
+    NOTES.with(|notes| notes.take())
+}
+
+#[test]
+fn explain() {
+    let worked_out = std::cell::Cell::new(0);
+    let work_out = |value| {
+        worked_out.set(worked_out.get() + 1);
+        value
+    };
+
+    // Other tests may have turned noting on already, since it's for every thread.
+    if !is_enabled() {
+        explain!(value = work_out(1));
+        assert_eq!(worked_out.get(), 0);
+        assert_eq!(take(), vec![]);
+    }
+
+    enable();
+    take();
+
+    explain!(line = "1abc2", first_digit = work_out(1), value = 6 * 2);
+    explain!(line = "pqr3stu8vwx", value = 38);
+    assert_eq!(worked_out.get(), 1);
+
+    let notes = take();
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[0].to_string(), "line 1abc2, first digit 1, value 12");
+    assert_eq!(notes[1].facts[1], ("value", "38".to_string()));
+    assert_eq!(take(), vec![]);
+}
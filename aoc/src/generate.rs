@@ -0,0 +1,106 @@
+//! Seeded random puzzle inputs, for stressing solutions with far more input than the official
+//! samples and comparing implementations against each other.
+//!
+//! The same seed and options always give the same input, on any platform, so a failure found with
+//! a generated input can be reproduced from just the command that made it.
+
+use std::ops::Range;
+
+/// How big an input to generate, along with knobs that only some days use.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Options {
+    /// Roughly how many records or rows to generate. What exactly this counts is up to each day.
+    pub size: usize,
+    /// How densely packed the day's interesting tiles or features are, from 0 to 1, or `None`
+    /// for the day's own default.
+    pub density: Option<f64>,
+}
+
+impl Options {
+    pub fn new(size: usize) -> Options {
+        Options {
+            size,
+            density: None,
+        }
+    }
+
+    /// The density, or `default` if none was given.
+    pub fn density_or(&self, default: f64) -> f64 {
+        self.density.unwrap_or(default).clamp(0.0, 1.0)
+    }
+}
+
+/// A small, fast pseudorandom number generator. Not fit for anything that needs to be
+/// unpredictable, only reproducible.
+///
+/// https://prng.di.unimi.it/splitmix64.c
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in the range, which mustn't be empty.
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "can't pick from an empty range");
+
+        // The slight bias towards low numbers for ranges that don't divide 2^64 doesn't matter here.
+        let span = (range.end - range.start) as u64;
+        range.start + (self.next_u64() % span) as usize
+    }
+
+    /// A number from 0 up to but not including 1.
+    pub fn fraction(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.fraction() < probability
+    }
+
+    /// One of the items, which mustn't be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        // https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i + 1));
+        }
+    }
+}
+
+#[test]
+fn rng() {
+    let mut rng = Rng::new(7);
+    let numbers: Vec<_> = (0..1000).map(|_| rng.range(10..20)).collect();
+    assert!(numbers.iter().all(|n| (10..20).contains(n)));
+    assert!((10..20).all(|n| numbers.contains(&n)));
+
+    // Reproducible from the seed alone.
+    let mut again = Rng::new(7);
+    assert!(numbers.iter().all(|&n| n == again.range(10..20)));
+    assert_ne!(Rng::new(8).next_u64(), Rng::new(7).next_u64());
+
+    let mut items = [1, 2, 3, 4, 5];
+    rng.shuffle(&mut items);
+    items.sort();
+    assert_eq!(items, [1, 2, 3, 4, 5]);
+
+    assert_eq!(Options::new(5).density_or(0.25), 0.25);
+    assert!((0..100).all(|_| (0.0..1.0).contains(&rng.fraction())));
+}
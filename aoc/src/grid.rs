@@ -0,0 +1,378 @@
+//! Primitives for puzzles laid out as a rectangle of cells.
+
+use crate::{parse, ParseError, Result};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+pub type Cell = (usize, usize); // row, column
+pub type Direction = (isize, isize); // row offset, column offset
+
+pub const DIR_N: Direction = (-1, 0);
+pub const DIR_S: Direction = (1, 0);
+pub const DIR_E: Direction = (0, 1);
+pub const DIR_W: Direction = (0, -1);
+
+// Clockwise from north.
+pub const CARDINAL_DIRECTIONS: [Direction; 4] = [DIR_N, DIR_E, DIR_S, DIR_W];
+
+// Includes diagonals, ordered top-left to bottom-right.
+pub const ADJACENT_DIRECTIONS: [Direction; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangle of cells, stored row by row in a single `Vec`. Most puzzles use characters, hence
+/// the default, but cells can be anything, such as an enum of the puzzle's tiles.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T = char> {
+    cells: Vec<T>,
+    row_count: usize,
+    col_count: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells isn't `row_count * col_count`.
+    pub fn from_cells(row_count: usize, col_count: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            row_count * col_count,
+            "cells don't fill the grid"
+        );
+
+        Grid {
+            cells,
+            row_count,
+            col_count,
+        }
+    }
+
+    pub fn filled(row_count: usize, col_count: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_cells(row_count, col_count, vec![value; row_count * col_count])
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    pub fn get(&self, (row, col): Cell) -> Option<&T> {
+        if row < self.row_count && col < self.col_count {
+            Some(&self.cells[row * self.col_count + col])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.col_count..(row + 1) * self.col_count]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.row_count).map(move |row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        (0..self.row_count).map(move |row| &self[(row, col)])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.col_count).map(move |col| self.column(col))
+    }
+
+    /// Every cell's position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Cell> {
+        let col_count = self.col_count;
+        (0..self.cells.len()).map(move |i| (i / col_count, i % col_count))
+    }
+
+    /// Every cell's position and value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Cell, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, that matches.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Cell> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(cell, _)| cell)
+    }
+
+    /// Returns the neighbouring cell in the given direction, or `None` if it falls outside the grid.
+    pub fn adjacent_cell(
+        &self,
+        (row, col): Cell,
+        (row_offset, col_offset): Direction,
+    ) -> Option<Cell> {
+        let row = row.checked_add_signed(row_offset)?;
+        let col = col.checked_add_signed(col_offset)?;
+
+        if row >= self.row_count || col >= self.col_count {
+            return None;
+        }
+
+        Some((row, col))
+    }
+
+    /// The neighbouring cells to the north, east, south and west that lie within the grid.
+    pub fn neighbours_4(&self, cell: Cell) -> impl Iterator<Item = Cell> + '_ {
+        CARDINAL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |direction| self.adjacent_cell(cell, direction))
+    }
+
+    /// The neighbouring cells, including diagonals, that lie within the grid.
+    pub fn neighbours_8(&self, cell: Cell) -> impl Iterator<Item = Cell> + '_ {
+        ADJACENT_DIRECTIONS
+            .into_iter()
+            .filter_map(move |direction| self.adjacent_cell(cell, direction))
+    }
+
+    /// Builds a grid of the same shape with each cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_cells(
+            self.row_count,
+            self.col_count,
+            self.cells.iter().map(f).collect(),
+        )
+    }
+
+    /// Builds a grid of the given shape where each cell comes from the cell at the position `f`
+    /// returns in this one. For an empty grid `f` is never called, so it can count back from the
+    /// last row or column without checking there is one.
+    fn rearrange(&self, row_count: usize, col_count: usize, f: impl Fn(Cell) -> Cell) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..row_count * col_count)
+            .map(|i| self[f((i / col_count, i % col_count))].clone())
+            .collect();
+
+        Grid::from_cells(row_count, col_count, cells)
+    }
+
+    /// Swaps rows and columns, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.col_count, self.row_count, |(row, col)| (col, row))
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.col_count, self.row_count, |(row, col)| {
+            (self.row_count - 1 - col, row)
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.col_count, self.row_count, |(row, col)| {
+            (col, self.col_count - 1 - row)
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.row_count, self.col_count, |(row, col)| {
+            (row, self.col_count - 1 - col)
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearrange(self.row_count, self.col_count, |(row, col)| {
+            (self.row_count - 1 - row, col)
+        })
+    }
+}
+
+impl<T> Index<Cell> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Cell) -> &T {
+        assert!(col < self.col_count, "column {col} is outside the grid");
+        &self.cells[row * self.col_count + col]
+    }
+}
+
+impl<T> IndexMut<Cell> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Cell) -> &mut T {
+        assert!(col < self.col_count, "column {col} is outside the grid");
+        &mut self.cells[row * self.col_count + col]
+    }
+}
+
+/// Prints one row per line, which for a grid of characters is the text it was parsed from.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{value}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses rows of characters, failing if they aren't all the same length.
+pub fn parse_grid(input: &str) -> Result<Grid> {
+    parse_lines(input, None, Some)
+}
+
+/// Like [`parse_grid`], but also fails on any character that isn't one of `tiles`.
+pub fn parse_grid_of(input: &str, tiles: &str) -> Result<Grid> {
+    parse_lines(input, Some(tiles), |c| tiles.contains(c).then_some(c))
+}
+
+/// Parses a grid of typed cells, converting each character with `tile`. Any character it returns
+/// `None` for is an error that lists `tiles` as the characters expected instead.
+pub fn parse_grid_with<T>(
+    input: &str,
+    tiles: &str,
+    tile: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>> {
+    parse_lines(input, Some(tiles), tile)
+}
+
+/// Builds a grid from rows the caller has already split up, such as one of several grids in an
+/// input. Every row must be a slice of the puzzle input.
+pub fn parse_rows(rows: &[&str], tiles: Option<&str>) -> Result<Grid> {
+    match tiles {
+        Some(tiles) => parse_rows_with(rows, Some(tiles), |c| tiles.contains(c).then_some(c)),
+        None => parse_rows_with(rows, None, Some),
+    }
+}
+
+fn parse_rows_with<T>(
+    rows: &[&str],
+    tiles: Option<&str>,
+    mut tile: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>> {
+    let col_count = rows.first().map_or(0, |row| row.chars().count());
+    let mut cells = Vec::with_capacity(rows.len() * col_count);
+
+    for row in rows {
+        for (i, c) in row.char_indices() {
+            let Some(value) = tile(c) else {
+                let message = format!(
+                    "unexpected tile `{c}`, expected one of `{}`",
+                    tiles.unwrap_or_default()
+                );
+
+                return Err(ParseError::new(parse::char_span(row, i), message));
+            };
+
+            cells.push(value);
+        }
+
+        let len = row.chars().count();
+
+        if len != col_count {
+            let message = format!("expected {col_count} columns like the first row, found {len}");
+            return Err(ParseError::new(row, message));
+        }
+    }
+
+    Ok(Grid::from_cells(rows.len(), col_count, cells))
+}
+
+fn parse_lines<T>(
+    input: &str,
+    tiles: Option<&str>,
+    tile: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>> {
+    // Blank lines at the end of the input aren't rows.
+    let rows: Vec<_> = parse::lines(input.trim_end()).collect();
+
+    if rows.iter().all(|row| row.is_empty()) {
+        return Err(ParseError::new(input, "expected a grid"));
+    }
+
+    parse_rows_with(&rows, tiles, tile)
+}
+
+#[test]
+fn grid() {
+    let grid = parse_grid("abc\ndef\n").unwrap();
+    assert_eq!((grid.row_count(), grid.col_count()), (2, 3));
+    assert_eq!(grid[(1, 0)], 'd');
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+
+    assert_eq!(grid.adjacent_cell((0, 0), DIR_N), None);
+    assert_eq!(grid.adjacent_cell((0, 0), DIR_W), None);
+    assert_eq!(grid.adjacent_cell((1, 2), DIR_S), None);
+    assert_eq!(grid.adjacent_cell((1, 2), DIR_E), None);
+    assert_eq!(grid.adjacent_cell((1, 1), DIR_N), Some((0, 1)));
+    assert_eq!(
+        grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+        [(0, 1), (1, 0)]
+    );
+    assert_eq!(
+        grid.neighbours_8((0, 0)).collect::<Vec<_>>(),
+        [(0, 1), (1, 0), (1, 1)]
+    );
+
+    let square = parse_grid("abc\ndef\nghi\n").unwrap();
+    assert_eq!(square.neighbours_8((1, 1)).count(), 8);
+
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+    assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    assert_eq!(
+        grid.map(|c| c.is_ascii_uppercase()),
+        Grid::filled(2, 3, false)
+    );
+
+    // Rearranging an empty grid gives an empty grid, turned round where that swaps its sides.
+    let empty = Grid::filled(0, 3, '.');
+    assert_eq!(empty.rotate_clockwise(), Grid::filled(3, 0, '.'));
+    assert_eq!(empty.rotate_counterclockwise(), Grid::filled(3, 0, '.'));
+    assert_eq!(empty.flip_horizontal(), empty);
+    assert_eq!(empty.flip_vertical(), empty);
+    assert_eq!(Grid::filled(2, 0, '.').transpose(), Grid::filled(0, 2, '.'));
+
+    let digits = parse_grid_with("12\n34", "0123456789", |c| c.to_digit(10)).unwrap();
+    assert_eq!(digits.iter().map(|(_, digit)| digit).sum::<u32>(), 10);
+
+    assert!(parse_grid("abc\nde\n").is_err());
+    assert!(parse_grid("\n").is_err());
+    assert!(parse_grid_of(".#.\n.#.", ".#").is_ok());
+    assert!(parse_grid_of(".#.\n.O.", ".#").is_err());
+}
@@ -0,0 +1,22 @@
+//! Building blocks shared by every day's solution.
+
+pub mod alloc;
+pub mod cycle;
+pub mod error;
+pub mod explain;
+pub mod generate;
+pub mod grid;
+pub mod math;
+pub mod oracle;
+pub mod parse;
+pub mod pool;
+pub mod property;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+
+pub use error::ParseError;
+pub use solution::Solution;
+
+pub type Result<T> = std::result::Result<T, ParseError>;
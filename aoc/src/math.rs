@@ -0,0 +1,166 @@
+//! Number theory that keeps coming up when puzzles have things repeating at different rates.
+//!
+//! Products of large numbers are where overflow creeps in, so anything that multiplies either
+//! works in a wider type or reports overflow instead of wrapping.
+
+/// The greatest common divisor of `a` and `b`, or the other if either is 0.
+pub fn gcd(a: u64, b: u64) -> u64 {
+    gcd_u128(a.into(), b.into()) as u64
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it doesn't fit in a `u64`.
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    // The LCM of two `u64`s always fits in a `u128`.
+    lcm_u128(a.into(), b.into())?.try_into().ok()
+}
+
+pub fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    // https://en.wikipedia.org/wiki/Greatest_common_divisor#Euclidean_algorithm
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it doesn't fit in a `u128`.
+pub fn lcm_u128(a: u128, b: u128) -> Option<u128> {
+    // https://en.wikipedia.org/wiki/Least_common_multiple#Calculation
+    // Dividing first keeps the product as small as it can be.
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd_u128(a, b)).checked_mul(b)
+}
+
+/// Finds the GCD of `a` and `b` along with `x` and `y` such that `a * x + b * y == gcd`, returned
+/// as `(gcd, x, y)`. The GCD is never negative.
+///
+/// https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` such that `a * x` is 1 modulo `modulus`, or `None` if there isn't one
+/// because `a` and `modulus` share a factor.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "modulus must be positive");
+
+    let (gcd, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (gcd == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Multiplies modulo `modulus` without the product overflowing.
+fn mul_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    let (mut a, mut b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    // Too big to multiply directly, so add up the powers of two of `a` that make up `b`.
+    let mut product = 0;
+
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, modulus);
+        }
+
+        a = add_mod(a, a, modulus);
+        b >>= 1;
+    }
+
+    product
+}
+
+/// Adds two numbers already reduced modulo `modulus`, without the sum overflowing when the modulus
+/// is more than half of `i128::MAX`.
+fn add_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    if a < modulus - b {
+        a + b
+    } else {
+        a - (modulus - b)
+    }
+}
+
+/// Solves a set of congruences `x ≡ residue (mod modulus)` with the Chinese remainder theorem.
+/// The moduli don't need to be coprime. Returns `(x, lcm)` where `x` is the smallest non-negative
+/// solution and every solution is `x` plus a multiple of the moduli's LCM, or `None` if the
+/// congruences contradict each other or the LCM doesn't fit in an `i128`.
+///
+/// https://en.wikipedia.org/wiki/Chinese_remainder_theorem#Generalization_to_non-coprime_moduli
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, lcm), (residue, modulus)| {
+            assert!(modulus > 0, "modulus must be positive");
+
+            // Step `x` along by multiples of the LCM so far until it also matches this
+            // congruence: `x + lcm * k ≡ residue (mod modulus)`.
+            let (gcd, inverse, _) = extended_gcd(lcm, modulus);
+            let difference = residue - x;
+
+            if difference % gcd != 0 {
+                return None;
+            }
+
+            let reduced_modulus = modulus / gcd;
+            let k = mul_mod(difference / gcd, inverse, reduced_modulus);
+            let new_lcm = lcm.checked_mul(reduced_modulus)?;
+            let x = (x + lcm * k).rem_euclid(new_lcm);
+
+            Some((x, new_lcm))
+        })
+}
+
+#[test]
+fn math() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(checked_lcm(4, 6), Some(12));
+    assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+    assert_eq!(
+        lcm_u128(u64::MAX.into(), (u64::MAX - 1).into()),
+        Some(u128::from(u64::MAX) * u128::from(u64::MAX - 1))
+    );
+
+    let (gcd, x, y) = extended_gcd(240, 46);
+    assert_eq!(gcd, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(6, 9), None);
+
+    // Near the top of the range even the sums along the way would overflow.
+    let huge = i128::MAX - 1;
+    assert_eq!(mul_mod(huge - 1, huge - 1, huge), 1);
+    assert_eq!(mul_mod(huge - 1, 2, huge), huge - 2);
+    assert_eq!(mul_mod(-1, huge - 3, huge), 3);
+
+    assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12))); // moduli that aren't coprime
+    assert_eq!(crt([(1, 4), (2, 6)]), None);
+    assert_eq!(crt([]), Some((0, 1)));
+
+    // Large enough moduli that multiplying the residues directly would overflow.
+    let big = (1 << 61) - 1;
+    let (x, lcm) = crt([(big - 1, big), (3, 1 << 62)]).unwrap();
+    assert_eq!((x % big, x % (1 << 62), lcm), (big - 1, 3, big << 62));
+}
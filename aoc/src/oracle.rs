@@ -0,0 +1,235 @@
+//! Differential testing: running a slow but obviously correct reference implementation alongside an
+//! optimised one on lots of generated inputs, and reporting the smallest input they disagree on.
+
+use crate::generate::Rng;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+
+/// An input the two implementations disagree on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch<A> {
+    pub seed: u64,     // the seed of the generated input it was minimised from
+    pub input: String, // as small as it could be made while still disagreeing
+    pub reference: A,
+    pub optimized: A,
+}
+
+impl<A: Display> Display for Mismatch<A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the reference gives {} but the optimized version gives {} for this input, minimized \
+             from seed {}:\n{}",
+            self.reference, self.optimized, self.seed, self.input
+        )
+    }
+}
+
+/// Runs both implementations on an input generated from each seed. At the first input they
+/// disagree on, that input is minimised and returned.
+///
+/// Either implementation returns `None` for an input that isn't valid, which can come up while
+/// minimising since inputs are cut down without regard for what they mean.
+pub fn cross_check<A: PartialEq>(
+    seeds: Range<u64>,
+    mut generate: impl FnMut(&mut Rng) -> String,
+    reference: impl Fn(&str) -> Option<A>,
+    optimized: impl Fn(&str) -> Option<A>,
+) -> Result<(), Mismatch<A>> {
+    let disagree = |input: &str| {
+        let reference = reference(input)?;
+        let optimized = optimized(input)?;
+        (reference != optimized).then_some((reference, optimized))
+    };
+
+    for seed in seeds {
+        let input = generate(&mut Rng::new(seed));
+
+        if disagree(&input).is_some() {
+            let input = minimize(input, |input| disagree(input).is_some());
+            let (reference, optimized) = disagree(&input).unwrap();
+
+            return Err(Mismatch {
+                seed,
+                input,
+                reference,
+                optimized,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Shrinks the input for as long as `is_interesting` still holds, by taking out lines and columns,
+/// making numbers smaller and taking out characters, until none of those make it any shorter.
+///
+/// Numbers that only work together, like a seed and the start of the range it has to fall in, can
+/// each only be made a little smaller at a time, so going on while they shrink could take forever.
+pub fn minimize(mut input: String, is_interesting: impl Fn(&str) -> bool) -> String {
+    loop {
+        let length = input.len();
+        input = remove_lines(input, &is_interesting);
+        input = remove_columns(input, &is_interesting);
+        input = shrink_numbers(input, &is_interesting);
+        input = remove_chars(input, &is_interesting);
+
+        if input.len() >= length {
+            return input;
+        }
+    }
+}
+
+/// Takes out runs of lines, halving the length of the runs each time.
+fn remove_lines(input: String, is_interesting: impl Fn(&str) -> bool) -> String {
+    let mut lines: Vec<_> = input.lines().map(str::to_string).collect();
+    let mut size = lines.len() / 2;
+
+    while size > 0 {
+        let mut start = 0;
+
+        while start + size <= lines.len() {
+            let mut kept = lines[..start].to_vec();
+            kept.extend_from_slice(&lines[start + size..]);
+
+            if is_interesting(&(kept.join("\n") + "\n")) {
+                lines = kept;
+            } else {
+                start += 1;
+            }
+        }
+
+        size /= 2;
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Takes out the same character from every line, which keeps grids rectangular.
+fn remove_columns(mut input: String, is_interesting: impl Fn(&str) -> bool) -> String {
+    let mut col = 0;
+
+    while input.lines().any(|line| line.chars().count() > col) {
+        let without: String = input
+            .lines()
+            .map(|line| {
+                let mut line: Vec<_> = line.chars().collect();
+
+                if col < line.len() {
+                    line.remove(col);
+                }
+
+                line.into_iter().chain(['\n']).collect::<String>()
+            })
+            .collect();
+
+        if is_interesting(&without) {
+            input = without;
+        } else {
+            col += 1;
+        }
+    }
+
+    input
+}
+
+/// Makes each number as small as it can be, assuming that if a number works then so do bigger
+/// ones, which holds often enough to be worth it.
+fn shrink_numbers(mut input: String, is_interesting: impl Fn(&str) -> bool) -> String {
+    let mut start = 0;
+
+    while let Some(offset) = input[start..].find(|c: char| c.is_ascii_digit()) {
+        start += offset;
+        let end = input[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(input.len(), |length| start + length);
+
+        let with = |number: u128| format!("{}{number}{}", &input[..start], &input[end..]);
+        let Ok(number) = input[start..end].parse::<u128>() else {
+            start = end;
+            continue;
+        };
+
+        let (mut low, mut high) = (0, number);
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            if is_interesting(&with(middle)) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+
+        input = with(high);
+        start += high.to_string().len();
+    }
+
+    input
+}
+
+/// Takes out single characters, other than line breaks and ones that would join two numbers into
+/// a bigger one.
+fn remove_chars(mut input: String, is_interesting: impl Fn(&str) -> bool) -> String {
+    let mut i = 0;
+
+    while let Some(c) = input[i..].chars().next() {
+        let joins_numbers = !c.is_ascii_digit()
+            && input[..i].ends_with(|c: char| c.is_ascii_digit())
+            && input[i + c.len_utf8()..].starts_with(|c: char| c.is_ascii_digit());
+
+        let without = format!("{}{}", &input[..i], &input[i + c.len_utf8()..]);
+
+        if c != '\n' && !joins_numbers && is_interesting(&without) {
+            input = without;
+        } else {
+            i += c.len_utf8();
+        }
+    }
+
+    input
+}
+
+#[test]
+fn oracle() {
+    // Summing numbers, except the "optimised" version counts any over 40 as 0.
+    let generate = |rng: &mut Rng| {
+        let numbers: Vec<_> = (0..20).map(|_| rng.range(0..50).to_string()).collect();
+        numbers.join("\n") + "\n"
+    };
+
+    let sum = |input: &str, limit: usize| -> Option<usize> {
+        input
+            .lines()
+            .map(|line| {
+                line.parse()
+                    .ok()
+                    .map(|n: usize| if n <= limit { n } else { 0 })
+            })
+            .sum()
+    };
+
+    let mismatch = cross_check(
+        0..10,
+        generate,
+        |input| sum(input, 100),
+        |input| sum(input, 40),
+    );
+    let mismatch = mismatch.unwrap_err();
+
+    assert_eq!(mismatch.seed, 0);
+    assert_eq!(mismatch.input, "41\n");
+    assert_eq!((mismatch.reference, mismatch.optimized), (41, 0));
+    assert!(mismatch.to_string().ends_with("seed 0:\n41\n"));
+
+    assert_eq!(
+        cross_check(
+            0..10,
+            generate,
+            |input| sum(input, 50),
+            |input| sum(input, 60)
+        ),
+        Ok(())
+    );
+}
@@ -0,0 +1,93 @@
+//! Helpers for picking apart puzzle input that fail with a [`ParseError`] rather than panicking.
+//!
+//! Every helper returns slices of the text it was given, so errors raised further down still point
+//! at the right place in the original input.
+
+use crate::{ParseError, Result};
+use std::str::FromStr;
+
+/// Splits the input into lines, ignoring trailing whitespace such as Windows line endings.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim_end)
+}
+
+/// Returns the next line, or an error pointing at the end of the input if there isn't one.
+pub fn next_line<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    input: &'a str,
+    expected: &str,
+) -> Result<&'a str> {
+    lines
+        .next()
+        .ok_or_else(|| ParseError::new(end(input), format!("expected {expected}")))
+}
+
+/// An empty slice at the very end of `text`, for errors about something that's missing.
+pub fn end(text: &str) -> &str {
+    &text[text.len()..]
+}
+
+pub fn number<T: FromStr>(text: &str) -> Result<T> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, format!("expected a number, found `{text}`")))
+}
+
+/// Parses a whitespace-separated list of numbers.
+pub fn numbers<T: FromStr>(text: &str) -> Result<Vec<T>> {
+    text.split_whitespace().map(number).collect()
+}
+
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, format!("expected `{delimiter}`")))
+}
+
+pub fn strip_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str> {
+    text.strip_prefix(prefix).ok_or_else(|| {
+        let span = &text[..text.len().min(prefix.len())];
+        ParseError::new(span, format!("expected `{prefix}`"))
+    })
+}
+
+pub fn strip_suffix<'a>(text: &'a str, suffix: &str) -> Result<&'a str> {
+    text.strip_suffix(suffix).ok_or_else(|| {
+        let span = &text[text.len().saturating_sub(suffix.len())..];
+        ParseError::new(span, format!("expected `{suffix}`"))
+    })
+}
+
+/// Returns the slice of `text` holding the character that starts at byte `index`.
+pub fn char_span(text: &str, index: usize) -> &str {
+    let len = text[index..].chars().next().map_or(0, char::len_utf8);
+    &text[index..index + len]
+}
+
+#[test]
+fn helpers() {
+    let input = "Time: 7 15\r\nDistance: 9 x\r\n";
+    let mut lines = lines(input);
+
+    let (label, numbers_str) = split_once(lines.next().unwrap(), ": ").unwrap();
+    assert_eq!(label, "Time");
+    assert_eq!(numbers::<usize>(numbers_str).unwrap(), vec![7, 15]);
+
+    let line = lines.next().unwrap();
+    assert_eq!(line, "Distance: 9 x");
+
+    let error = numbers::<usize>(line).unwrap_err().locate(input);
+    assert_eq!(error.message(), "expected a number, found `Distance:`");
+
+    let error = number::<usize>(&line[12..]).unwrap_err().locate(input);
+    assert_eq!(error.location().unwrap().line, 2);
+    assert_eq!(error.location().unwrap().column, 13);
+
+    let error = next_line(&mut lines, input, "a line")
+        .unwrap_err()
+        .locate(input);
+    assert_eq!(error.message(), "expected a line");
+    assert_eq!(error.location().unwrap().line, 3);
+
+    assert!(strip_prefix("Card 1", "Game ").is_err());
+    assert_eq!(strip_suffix("(AAA)", ")").unwrap(), "(AAA");
+    assert_eq!(char_span("aé!", 1), "é");
+}
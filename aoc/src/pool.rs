@@ -0,0 +1,152 @@
+//! Runs independent tasks on several threads at once, like the runner's days or the beams day 16
+//! tries, with one budget of threads shared by everything running, however it's nested.
+//!
+//! Each [`map`] borrows as many helper threads from the budget as it has spare and works alongside
+//! them, so once the budget's used up, tasks simply run on the thread that asked for them. The
+//! tasks are dealt out evenly between the threads, and a thread that runs out steals from the back
+//! of another's queue, so a few slow tasks don't hold the rest up.
+
+use std::collections::VecDeque;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+static JOBS: AtomicUsize = AtomicUsize::new(0); // 0 until set, meaning one per core
+static HELPERS_IN_USE: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets how many threads may be working at once, counting the one that started the work.
+pub fn set_jobs(jobs: usize) {
+    JOBS.store(jobs.max(1), Ordering::Relaxed);
+}
+
+/// How many threads may be working at once, by default one per core.
+pub fn jobs() -> usize {
+    match JOBS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+        jobs => jobs,
+    }
+}
+
+/// Applies `f` to every item, using whatever threads the budget has spare, and returns the results
+/// in the same order as the items whichever thread worked each out.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let helpers = Helpers::borrow(items.len().saturating_sub(1));
+
+    if helpers.count == 0 {
+        return items.iter().map(f).collect();
+    }
+
+    let thread_count = helpers.count + 1;
+    let queues: Vec<_> = (0..thread_count)
+        .map(|t| {
+            let dealt = (t * items.len() / thread_count)..((t + 1) * items.len() / thread_count);
+            Mutex::new(dealt.collect::<VecDeque<_>>())
+        })
+        .collect();
+
+    let work = |t: usize| {
+        let mut results = Vec::new();
+
+        while let Some(i) = next_task(&queues, t) {
+            results.push((i, f(&items[i])));
+        }
+
+        results
+    };
+
+    let mut results: Vec<_> = thread::scope(|scope| {
+        let work = &work;
+        let handles: Vec<_> = (1..thread_count)
+            .map(|t| scope.spawn(move || work(t)))
+            .collect();
+
+        let mut results = work(0);
+
+        for handle in handles {
+            match handle.join() {
+                Ok(helped) => results.extend(helped),
+                Err(panic) => panic::resume_unwind(panic),
+            }
+        }
+
+        results
+    });
+
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// The next task for thread `t`: the first left in its own queue, or failing that the last in
+/// another's.
+fn next_task(queues: &[Mutex<VecDeque<usize>>], t: usize) -> Option<usize> {
+    if let Some(i) = queues[t].lock().unwrap().pop_front() {
+        return Some(i);
+    }
+
+    (1..queues.len())
+        .map(|offset| (t + offset) % queues.len())
+        .find_map(|other| queues[other].lock().unwrap().pop_back())
+}
+
+/// Helper threads borrowed from the budget, given back when dropped, even if a task panics.
+struct Helpers {
+    count: usize,
+}
+
+impl Helpers {
+    fn borrow(wanted: usize) -> Helpers {
+        let spare = jobs() - 1;
+        let mut count = 0;
+
+        let _ = HELPERS_IN_USE.fetch_update(Ordering::AcqRel, Ordering::Acquire, |in_use| {
+            count = wanted.min(spare.saturating_sub(in_use));
+            Some(in_use + count)
+        });
+
+        Helpers { count }
+    }
+}
+
+impl Drop for Helpers {
+    fn drop(&mut self) {
+        HELPERS_IN_USE.fetch_sub(self.count, Ordering::AcqRel);
+    }
+}
+
+#[test]
+fn pool() {
+    use std::time::Duration;
+
+    let items: Vec<u64> = (0..100).collect();
+
+    // Whatever order they finish in, the results come back in the items' order.
+    let squares = map(&items, |&n| {
+        thread::sleep(Duration::from_micros(100 - n));
+        n * n
+    });
+
+    assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+
+    // Nested maps share the budget rather than each taking their own.
+    let (working, most_working) = (AtomicUsize::new(0), AtomicUsize::new(0));
+
+    let nested = map(&items[..10], |&n| {
+        let products = map(&items[..10], |&m| {
+            let now_working = working.fetch_add(1, Ordering::SeqCst) + 1;
+            most_working.fetch_max(now_working, Ordering::SeqCst);
+            thread::sleep(Duration::from_micros(200));
+            working.fetch_sub(1, Ordering::SeqCst);
+            n * m
+        });
+
+        products.iter().sum::<u64>()
+    });
+
+    assert_eq!(nested[3], 3 * 45);
+    assert!(most_working.into_inner() <= jobs());
+    assert_eq!(map(&[] as &[u64], |&n| n), vec![]);
+
+    let panicked = panic::catch_unwind(|| map(&items, |&n| assert!(n != 50)));
+    assert!(panicked.is_err());
+}
@@ -0,0 +1,83 @@
+//! Property testing: checking that something holds for lots of generated inputs, and when it
+//! doesn't, cutting the input down to as little as it still fails for.
+
+use crate::generate::Rng;
+use crate::oracle;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+
+/// An input a property doesn't hold for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Counterexample {
+    pub seed: u64,     // the seed of the generated input it was minimised from
+    pub input: String, // as small as it could be made while still failing
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "minimized from seed {}:\n{}", self.seed, self.input)
+    }
+}
+
+/// Checks the property on an input generated from each seed, minimising the first input it
+/// doesn't hold for.
+///
+/// The property gives `None` for an input it doesn't apply to, like one minimising has cut down
+/// until it no longer parses.
+pub fn check(
+    seeds: Range<u64>,
+    mut generate: impl FnMut(&mut Rng) -> String,
+    holds: impl Fn(&str) -> Option<bool>,
+) -> Result<(), Counterexample> {
+    let fails = |input: &str| holds(input) == Some(false);
+
+    for seed in seeds {
+        let input = generate(&mut Rng::new(seed));
+
+        if fails(&input) {
+            let input = oracle::minimize(input, fails);
+            return Err(Counterexample { seed, input });
+        }
+    }
+
+    Ok(())
+}
+
+/// Like [`check`], but panics with the counterexample, for tests.
+#[track_caller]
+pub fn assert_holds(
+    property: &str,
+    seeds: Range<u64>,
+    generate: impl FnMut(&mut Rng) -> String,
+    holds: impl Fn(&str) -> Option<bool>,
+) {
+    if let Err(counterexample) = check(seeds, generate, holds) {
+        panic!("`{property}` is false for this input, {counterexample}");
+    }
+}
+
+#[test]
+fn property() {
+    // Every other character of a line, which only keeps the line's length for short lines.
+    let generate = |rng: &mut Rng| {
+        let length = rng.range(0..30);
+        (0..length)
+            .map(|_| *rng.choose(&['a', 'b', 'c']))
+            .collect::<String>()
+            + "\n"
+    };
+
+    let halving_keeps_length = |input: &str| {
+        let line = input.lines().next()?;
+        let halved: String = line.chars().step_by(2).collect();
+        Some(halved.len() == line.len())
+    };
+
+    let counterexample = check(0..10, generate, halving_keeps_length).unwrap_err();
+    assert_eq!(counterexample.input.len(), "ab\n".len());
+
+    assert_eq!(
+        check(0..10, generate, |input| Some(input.ends_with('\n'))),
+        Ok(())
+    );
+}
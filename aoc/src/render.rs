@@ -0,0 +1,416 @@
+//! Drawing grid puzzles as images: a grid of tiles with layers of highlighted cells over it, like
+//! a loop's path or the tiles a beam passes through, written out as PPM, PNG or SVG.
+
+use crate::grid::{Cell, Grid};
+use std::fmt::Write;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// Parses a colour written like `ff8800` or `#ff8800`.
+    pub fn parse(text: &str) -> Option<Rgb> {
+        let hex = text.strip_prefix('#').unwrap_or(text);
+
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |i| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// This colour with `other` laid over it, `opacity` of the way through.
+    fn blend(self, other: Rgb, opacity: f64) -> Rgb {
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * opacity).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+}
+
+/// What to draw: a grid of tiles, and layers of cells to highlight over them.
+pub struct Scene {
+    pub tiles: Grid<char>,
+    pub layers: Vec<Layer>, // drawn in order, so later layers show over earlier ones
+}
+
+pub struct Layer {
+    pub name: &'static str, // what the palette calls it
+    pub cells: Vec<Cell>,
+}
+
+impl Scene {
+    pub fn new(tiles: Grid<char>) -> Scene {
+        Scene {
+            tiles,
+            layers: Vec::new(),
+        }
+    }
+
+    pub fn with_layer(
+        mut self,
+        name: &'static str,
+        cells: impl IntoIterator<Item = Cell>,
+    ) -> Scene {
+        let cells = cells.into_iter().collect();
+        self.layers.push(Layer { name, cells });
+        self
+    }
+
+    /// The colour of every cell, with the layers blended over the tiles.
+    pub fn colors(&self, palette: &Palette) -> Grid<Rgb> {
+        let mut colors = self.tiles.map(|&tile| palette.tile(tile));
+
+        for layer in &self.layers {
+            let color = palette.layer(layer.name);
+
+            for &cell in &layer.cells {
+                colors[cell] = colors[cell].blend(color, LAYER_OPACITY);
+            }
+        }
+
+        colors
+    }
+}
+
+/// Scenes one after another, like the steps of a simulation.
+pub type Frames = Box<dyn Iterator<Item = Scene>>;
+
+/// How much layers hide the tiles under them. Not entirely, so mirrors still show through a beam.
+const LAYER_OPACITY: f64 = 0.7;
+
+/// The colours to draw tiles and layers in, by the tile's character or the layer's name.
+#[derive(Clone, Debug)]
+pub struct Palette {
+    colors: Vec<(String, Rgb)>,
+    other: Rgb, // for any tile or layer without its own colour
+}
+
+impl Default for Palette {
+    fn default() -> Palette {
+        let colors = [
+            (".", Rgb(0x0f, 0x0f, 0x23)),
+            ("#", Rgb(0xcc, 0xcc, 0xcc)),
+            ("O", Rgb(0xff, 0xff, 0x66)),
+            ("S", Rgb(0x00, 0xcc, 0x00)),
+            ("path", Rgb(0xff, 0x99, 0x00)),
+            ("inside", Rgb(0x00, 0x99, 0x00)),
+            ("outside", Rgb(0x33, 0x33, 0x66)),
+            ("energized", Rgb(0xff, 0xff, 0x66)),
+            ("expanded", Rgb(0x66, 0x33, 0x99)),
+            ("beam", Rgb(0xff, 0xff, 0xff)),
+        ];
+
+        Palette {
+            colors: colors
+                .into_iter()
+                .map(|(name, color)| (name.to_string(), color))
+                .collect(),
+            other: Rgb(0x66, 0x66, 0x77),
+        }
+    }
+}
+
+impl Palette {
+    /// The default palette with some colours replaced, given like `path=ff0000,#=333333`.
+    pub fn with_overrides(spec: &str) -> Result<Palette, String> {
+        let mut palette = Palette::default();
+
+        for entry in spec.split(',').filter(|entry| !entry.is_empty()) {
+            let (name, color) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("expected a colour like `path=ff8800`, found `{entry}`"))?;
+
+            let color = Rgb::parse(color)
+                .ok_or_else(|| format!("expected a colour like `ff8800`, found `{color}`"))?;
+
+            palette.colors.retain(|(existing, _)| existing != name);
+            palette.colors.push((name.to_string(), color));
+        }
+
+        Ok(palette)
+    }
+
+    fn get(&self, name: &str) -> Rgb {
+        let color = self.colors.iter().find(|(existing, _)| existing == name);
+        color.map_or(self.other, |&(_, color)| color)
+    }
+
+    fn tile(&self, tile: char) -> Rgb {
+        self.get(tile.encode_utf8(&mut [0; 4]))
+    }
+
+    fn layer(&self, name: &str) -> Rgb {
+        self.get(name)
+    }
+
+    fn background(&self) -> Rgb {
+        self.tile('.')
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl Format {
+    /// The format a file's extension asks for.
+    pub fn of(path: &Path) -> Result<Format, String> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("ppm") => Ok(Format::Ppm),
+            Some("png") => Ok(Format::Png),
+            Some("svg") => Ok(Format::Svg),
+            Some(extension) => Err(format!(
+                "unknown image format `.{extension}`, expected .ppm, .png or .svg"
+            )),
+            None => Err("the image's file name needs an extension: .ppm, .png or .svg".into()),
+        }
+    }
+}
+
+/// Draws the scene with each cell `cell_size` pixels square, encoded in the format.
+pub fn render(scene: &Scene, palette: &Palette, cell_size: usize, format: Format) -> Vec<u8> {
+    let colors = scene.colors(palette);
+
+    match format {
+        Format::Ppm => ppm(&colors, cell_size),
+        Format::Png => png(&colors, cell_size),
+        Format::Svg => svg(&colors, palette.background(), cell_size).into_bytes(),
+    }
+}
+
+/// The scene as coloured blocks for a terminal that understands 24-bit ANSI colours, each cell two
+/// characters wide so it comes out roughly square.
+pub fn ansi(scene: &Scene, palette: &Palette) -> String {
+    let mut text = String::new();
+
+    for row in scene.colors(palette).rows() {
+        let mut previous = None;
+
+        for &color in row {
+            if previous != Some(color) {
+                let Rgb(r, g, b) = color;
+                write!(text, "\x1b[48;2;{r};{g};{b}m").unwrap();
+                previous = Some(color);
+            }
+
+            text += "  ";
+        }
+
+        text += "\x1b[0m\n";
+    }
+
+    text
+}
+
+/// The scene as plain text, for comparing in tests: the tiles, then each layer as a grid with its
+/// cells marked `#`.
+pub fn text(scene: &Scene) -> String {
+    let mut text = scene.tiles.to_string();
+
+    for layer in &scene.layers {
+        let mut mask = scene.tiles.map(|_| '.');
+
+        for &cell in &layer.cells {
+            mask[cell] = '#';
+        }
+
+        write!(text, "\n{}:\n{mask}", layer.name).unwrap();
+    }
+
+    text
+}
+
+/// Every pixel's colour, a row at a time.
+fn pixel_rows(colors: &Grid<Rgb>, cell_size: usize) -> impl Iterator<Item = Vec<u8>> + '_ {
+    colors.rows().flat_map(move |row| {
+        let pixels: Vec<_> = row
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b].repeat(cell_size))
+            .collect();
+
+        std::iter::repeat_n(pixels, cell_size)
+    })
+}
+
+// https://netpbm.sourceforge.net/doc/ppm.html
+fn ppm(colors: &Grid<Rgb>, cell_size: usize) -> Vec<u8> {
+    let (width, height) = (
+        colors.col_count() * cell_size,
+        colors.row_count() * cell_size,
+    );
+    let mut bytes = format!("P6\n{width} {height}\n255\n").into_bytes();
+    pixel_rows(colors, cell_size).for_each(|row| bytes.extend(row));
+    bytes
+}
+
+// https://www.w3.org/TR/png/
+fn png(colors: &Grid<Rgb>, cell_size: usize) -> Vec<u8> {
+    let (width, height) = (
+        colors.col_count() * cell_size,
+        colors.row_count() * cell_size,
+    );
+
+    let mut header = Vec::new();
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]); // 8 bits per channel, RGB, no interlacing
+
+    // Each row starts with the filter it uses, which is always none.
+    let mut pixels = Vec::new();
+
+    for row in pixel_rows(colors, cell_size) {
+        pixels.push(0);
+        pixels.extend(row);
+    }
+
+    let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut bytes, b"IHDR", &header);
+    png_chunk(&mut bytes, b"IDAT", &zlib_stored(&pixels));
+    png_chunk(&mut bytes, b"IEND", &[]);
+    bytes
+}
+
+fn png_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend((data.len() as u32).to_be_bytes());
+    bytes.extend(kind);
+    bytes.extend(data);
+
+    let crc = crc32(kind.iter().chain(data));
+    bytes.extend(crc.to_be_bytes());
+}
+
+/// The data wrapped up as a zlib stream without compressing it, which is still a valid one. The
+/// images are mostly runs of the same colour, so compressing them would help, but not enough to be
+/// worth writing a compressor for.
+///
+/// https://www.rfc-editor.org/rfc/rfc1950 and https://www.rfc-editor.org/rfc/rfc1951
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+
+    if blocks.peek().is_none() {
+        bytes.extend([1, 0, 0, 0xff, 0xff]); // an empty final block
+    }
+
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        let length = block.len() as u16;
+
+        bytes.push(is_final as u8);
+        bytes.extend(length.to_le_bytes());
+        bytes.extend((!length).to_le_bytes());
+        bytes.extend(block);
+    }
+
+    bytes.extend(adler32(data).to_be_bytes());
+    bytes
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let crc = bytes.into_iter().fold(!0u32, |mut crc, &byte| {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+        }
+
+        crc
+    });
+
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
+
+/// A rectangle for each run of same coloured cells in a row, over the background.
+fn svg(colors: &Grid<Rgb>, background: Rgb, cell_size: usize) -> String {
+    let (width, height) = (
+        colors.col_count() * cell_size,
+        colors.row_count() * cell_size,
+    );
+    let hex = |Rgb(r, g, b): Rgb| format!("#{r:02x}{g:02x}{b:02x}");
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n\
+         <rect width=\"{width}\" height=\"{height}\" fill=\"{}\"/>\n",
+        hex(background)
+    );
+
+    for (row, colors) in colors.rows().enumerate() {
+        let mut col = 0;
+
+        while col < colors.len() {
+            let color = colors[col];
+            let run = colors[col..].iter().take_while(|&&c| c == color).count();
+
+            if color != background {
+                let (x, y, run_width) = (col * cell_size, row * cell_size, run * cell_size);
+                let fill = hex(color);
+
+                writeln!(
+                    svg,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{run_width}\" height=\"{cell_size}\" \
+                     fill=\"{fill}\"/>"
+                )
+                .unwrap();
+            }
+
+            col += run;
+        }
+    }
+
+    svg + "</svg>\n"
+}
+
+#[test]
+fn rendering() {
+    let tiles = crate::grid::parse_grid("#.\n.O\n").unwrap();
+    let scene = Scene::new(tiles).with_layer("path", [(0, 1)]);
+    let palette = Palette::with_overrides("#=ffffff,.=000000,path=#ff0000").unwrap();
+
+    // The path is blended over the background it's on.
+    let ppm = render(&scene, &palette, 2, Format::Ppm);
+    assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+    assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+    assert_eq!(&ppm[11..17], &[255, 255, 255, 255, 255, 255]);
+    assert_eq!(&ppm[17..20], &[179, 0, 0]);
+
+    let png = render(&scene, &palette, 2, Format::Png);
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x04\0\0\0\x04"));
+    assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+    assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    assert_eq!(crc32(b"123456789"), 0xcbf43926);
+
+    let svg = String::from_utf8(render(&scene, &palette, 2, Format::Svg)).unwrap();
+    assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"2\" height=\"2\" fill=\"#b30000\"/>"));
+    assert_eq!(svg.matches("<rect").count(), 4); // background, #, path, O
+
+    assert_eq!(text(&scene), "#.\n.O\n\npath:\n.#\n..\n");
+    assert!(ansi(&scene, &palette).starts_with("\x1b[48;2;255;255;255m  \x1b[48;2;179;0;0m  "));
+
+    assert_eq!(Format::of("out.PNG".as_ref()), Ok(Format::Png));
+    assert!(Format::of("out.jpg".as_ref()).is_err());
+    assert!(Palette::with_overrides("path=orange").is_err());
+}
@@ -0,0 +1,269 @@
+//! Graph searches over any kind of state, given a function that lists where each state leads.
+//!
+//! States only need to be hashable, so a state can be as simple as a [`Cell`](crate::grid::Cell)
+//! or carry whatever else the puzzle cares about, like the direction of travel.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// How much work a search did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub visited: usize,  // distinct states reached
+    pub expanded: usize, // states whose successors were looked at
+}
+
+/// A path to a goal, from the start state to the goal state inclusive.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Found<S> {
+    pub path: Vec<S>,
+    pub cost: usize,
+    pub stats: Stats,
+}
+
+struct Node<S> {
+    state: S,
+    parent: Option<usize>, // index of the node this one was reached from
+    cost: usize,
+}
+
+/// Every state a search reached, with the cheapest known way to get to each one.
+pub struct Reached<S> {
+    nodes: Vec<Node<S>>,
+    indices: HashMap<S, usize>, // index of each state's cheapest node
+    pub stats: Stats,
+}
+
+impl<S: Clone + Eq + Hash> Reached<S> {
+    fn new(start: S) -> Reached<S> {
+        Reached {
+            indices: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![Node {
+                state: start,
+                parent: None,
+                cost: 0,
+            }],
+            stats: Stats {
+                visited: 1,
+                expanded: 0,
+            },
+        }
+    }
+
+    /// Adds the state unless it's already been reached at least as cheaply, returning the index
+    /// of its new node if it was added.
+    fn reach(&mut self, state: S, parent: usize, cost: usize) -> Option<usize> {
+        let previous = self.indices.get(&state).map(|&i| self.nodes[i].cost);
+
+        if previous.is_some_and(|previous| previous <= cost) {
+            return None;
+        }
+
+        if previous.is_none() {
+            self.stats.visited += 1;
+        }
+
+        self.indices.insert(state.clone(), self.nodes.len());
+        self.nodes.push(Node {
+            state,
+            parent: Some(parent),
+            cost,
+        });
+
+        Some(self.nodes.len() - 1)
+    }
+
+    fn path_from(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![self.nodes[index].state.clone()];
+
+        while let Some(parent) = self.nodes[index].parent {
+            path.push(self.nodes[parent].state.clone());
+            index = parent;
+        }
+
+        path.reverse();
+        path
+    }
+
+    fn found(&self, index: usize) -> Found<S> {
+        Found {
+            path: self.path_from(index),
+            cost: self.nodes[index].cost,
+            stats: self.stats,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn contains(&self, state: &S) -> bool {
+        self.indices.contains_key(state)
+    }
+
+    /// The cost of the cheapest way found to reach the state.
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        self.indices.get(state).map(|&i| self.nodes[i].cost)
+    }
+
+    /// The cheapest way found from the start to the state.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.indices.get(state).map(|&i| self.path_from(i))
+    }
+
+    /// Every state reached along with its cost, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&S, usize)> {
+        self.indices
+            .iter()
+            .map(|(state, &i)| (state, self.nodes[i].cost))
+    }
+}
+
+/// Explores outwards from `start` one step at a time, so every state is reached in the fewest
+/// steps possible. Stops early once `is_goal` accepts a state.
+fn breadth_first<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Reached<S>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = Reached::new(start);
+    let mut frontier = VecDeque::from([0]);
+
+    while let Some(index) = frontier.pop_front() {
+        if is_goal(&reached.nodes[index].state) {
+            return (reached, Some(index));
+        }
+
+        reached.stats.expanded += 1;
+        let cost = reached.nodes[index].cost + 1;
+
+        for next in successors(&reached.nodes[index].state) {
+            frontier.extend(reached.reach(next, index, cost));
+        }
+    }
+
+    (reached, None)
+}
+
+/// Finds the path to a goal with the fewest steps.
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (reached, goal) = breadth_first(start, successors, is_goal);
+    goal.map(|index| reached.found(index))
+}
+
+/// Finds every state that can be reached from `start`, each in the fewest steps possible.
+pub fn reachable<S, I>(start: S, successors: impl FnMut(&S) -> I) -> Reached<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(start, successors, |_| false).0
+}
+
+/// Finds the cheapest path to a goal, where `successors` gives the cost of each step.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// Like [`dijkstra`], but explores the states `heuristic` estimates are closest to a goal first.
+/// The path is only guaranteed to be the cheapest if the heuristic never overestimates.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), 0))]); // estimate, node
+    let mut reached = Reached::new(start);
+
+    while let Some(Reverse((_, index))) = frontier.pop() {
+        let node = &reached.nodes[index];
+
+        // A cheaper way to this state was found after this one was queued.
+        if reached.indices[&node.state] != index {
+            continue;
+        }
+
+        if is_goal(&node.state) {
+            return Some(reached.found(index));
+        }
+
+        reached.stats.expanded += 1;
+        let cost = node.cost;
+
+        for (next, step_cost) in successors(&reached.nodes[index].state) {
+            let estimate = heuristic(&next);
+
+            if let Some(next_index) = reached.reach(next, index, cost + step_cost) {
+                frontier.push(Reverse((cost + step_cost + estimate, next_index)));
+            }
+        }
+    }
+
+    None
+}
+
+#[test]
+fn search() {
+    use crate::grid::{self, Cell};
+
+    // Wading through water (`~`) takes five times as long as walking.
+    let maze = grid::parse_grid("S~~~E\n.###.\n.....\n").unwrap();
+    let start = (0, 0);
+    let goal = (0, 4);
+
+    let open = |cell: &Cell| maze.neighbours_4(*cell).filter(|&next| maze[next] != '#');
+    let timed = |cell: &Cell| open(cell).map(|next| (next, if maze[next] == '~' { 5 } else { 1 }));
+    let distance = |&(row, col): &Cell| row.abs_diff(goal.0) + col.abs_diff(goal.1);
+
+    let fewest_steps = bfs(start, open, |&cell| cell == goal).unwrap();
+    assert_eq!(fewest_steps.cost, 4);
+    assert_eq!(fewest_steps.path, [(0, 0), (0, 1), (0, 2), (0, 3), (0, 4)]);
+
+    let quickest = dijkstra(start, timed, |&cell| cell == goal).unwrap();
+    assert_eq!(quickest.cost, 8);
+    assert_eq!(quickest.path.len(), 9);
+    assert_eq!(quickest.path[4], (2, 2));
+
+    let guided = astar(start, timed, distance, |&cell| cell == goal).unwrap();
+    assert_eq!(guided.cost, 8);
+    assert!(guided.stats.expanded <= quickest.stats.expanded);
+
+    let reached = reachable(start, open);
+    assert_eq!(reached.len(), 12);
+    assert_eq!(reached.stats.visited, 12);
+    assert_eq!(reached.cost(&goal), Some(4));
+    assert_eq!(reached.path_to(&goal), Some(fewest_steps.path));
+    assert!(!reached.contains(&(1, 1)));
+
+    assert_eq!(bfs(start, open, |&cell| cell == (1, 1)), None);
+}
@@ -0,0 +1,266 @@
+//! The shape every day's solution takes: parse the input once, then solve each part from it.
+
+use crate::explain::{self, Note};
+use crate::render::{Frames, Scene};
+use crate::{alloc, ParseError, Result};
+use std::fmt::Display;
+use std::io::BufRead;
+use std::iter;
+use std::time::{Duration, Instant};
+
+pub trait Solution {
+    const DAY: u8;
+
+    /// The model built from the puzzle input, shared by both parts.
+    type Parsed;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    /// Solves a part, or reports why the parsed input can't be solved, like a line with no digit
+    /// in it for day 1, when that isn't something parsing can rule out for both parts.
+    fn part_1(parsed: &Self::Parsed) -> Result<Self::Answer>;
+    fn part_2(parsed: &Self::Parsed) -> Result<Self::Answer>;
+}
+
+/// A solution whose input is a list of records, one per line, that can be worked through a line at
+/// a time rather than read in whole, so inputs of any size can be solved without holding them in
+/// memory.
+pub trait Streaming: Solution {
+    type Record;
+
+    /// What a part keeps of the records seen so far, ideally a running total.
+    type Totals: Default;
+
+    fn parse_line(line: &str) -> Result<Self::Record>;
+    fn add_1(totals: &mut Self::Totals, record: &Self::Record) -> Result<()>;
+    fn add_2(totals: &mut Self::Totals, record: &Self::Record) -> Result<()>;
+    fn finish(totals: Self::Totals) -> Result<Self::Answer>;
+}
+
+pub struct Report {
+    pub parse: Measurement,
+    pub parts: Vec<PartReport>,
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub answer: String,
+    pub measurement: Measurement,
+    pub notes: Vec<Note>, // only kept when `explain::enable` has been called
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Measurement {
+    pub time: Duration,
+    pub allocations: usize, // only counted when `alloc::CountingAllocator` is installed
+}
+
+impl Measurement {
+    fn add(&mut self, other: Measurement) {
+        self.time += other.time;
+        self.allocations += other.allocations;
+    }
+
+    pub fn of<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+        let allocations = alloc::allocations();
+        let start = Instant::now();
+        let result = f();
+        let time = start.elapsed();
+
+        let measurement = Measurement {
+            time,
+            allocations: alloc::allocations() - allocations,
+        };
+
+        (result, measurement)
+    }
+}
+
+/// A solution whose parsed input can be written back out as an input, so the parser can be checked
+/// against it. What's printed needn't match the original input exactly, only parse to the same.
+pub trait Print: Solution {
+    fn print(parsed: &Self::Parsed) -> String;
+}
+
+/// A grid puzzle that can be drawn, with whatever it works out highlighted over its input.
+pub trait Render: Solution {
+    fn render(parsed: &Self::Parsed) -> Scene;
+}
+
+/// A puzzle whose solution plays out step by step, like a simulation, that can be watched.
+pub trait Animate: Solution {
+    fn animate(parsed: Self::Parsed) -> Frames;
+}
+
+/// A puzzle whose parsed input can be asked about, like where a seed ends up, for poking at it
+/// interactively.
+pub trait Explore: Solution {
+    const QUERIES: &'static [Query];
+
+    /// Answers one of [`QUERIES`](Explore::QUERIES), which always gets as many arguments as it
+    /// names.
+    fn query(parsed: &Self::Parsed, query: &str, args: &[&str]) -> Result<String>;
+}
+
+/// Something an [`Explore`] puzzle can be asked, with the arguments it takes and what it answers.
+pub struct Query {
+    pub name: &'static str,
+    pub args: &'static [&'static str],
+    pub about: &'static str,
+}
+
+/// Answers a query about a parsed input, hiding what type it was parsed to.
+type Answer = Box<dyn Fn(&str, &[&str]) -> Result<String>>;
+
+/// A parsed input kept around to be asked about. See [`Explore`].
+pub struct Session {
+    pub queries: &'static [Query],
+    answer: Answer,
+}
+
+impl Session {
+    /// Answers the query, as long as it's one the puzzle knows, given the arguments it takes.
+    pub fn ask(&self, query: &str, args: &[&str]) -> Result<String> {
+        let Some(known) = self.queries.iter().find(|known| known.name == query) else {
+            return Err(ParseError::new(query, format!("unknown query `{query}`")));
+        };
+
+        if args.len() != known.args.len() {
+            let usage = iter::once(known.name).chain(known.args.iter().copied());
+            let message = format!("usage: {}", usage.collect::<Vec<_>>().join(" "));
+            return Err(ParseError::new(query, message));
+        }
+
+        (self.answer)(query, args)
+    }
+}
+
+/// Parses the input, pointing any error at where it happened.
+pub fn parse<S: Solution>(input: &str) -> Result<S::Parsed> {
+    S::parse(input).map_err(|error| error.locate(input).with_day(S::DAY))
+}
+
+/// Parses the input once and solves the requested parts, measuring each step.
+pub fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<Report> {
+    let (parsed, parse) = Measurement::of(|| parse::<S>(input));
+    let parsed = parsed?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, measurement) = Measurement::of(|| match part {
+                1 => S::part_1(&parsed),
+                _ => S::part_2(&parsed),
+            });
+
+            Ok(PartReport {
+                part,
+                answer: answer.map_err(|error| error.with_day(S::DAY))?.to_string(),
+                measurement,
+                notes: explain::take(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Report { parse, parts })
+}
+
+/// Parses the input and draws it. See [`Render`].
+pub fn render<S: Render>(input: &str) -> Result<Scene> {
+    parse::<S>(input).map(|parsed| S::render(&parsed))
+}
+
+/// Parses the input and plays it out. See [`Animate`].
+pub fn animate<S: Animate>(input: &str) -> Result<Frames> {
+    parse::<S>(input).map(S::animate)
+}
+
+/// Parses the input and keeps it to be asked about. See [`Explore`].
+pub fn explore<S: Explore>(input: &str) -> Result<Session>
+where
+    S::Parsed: 'static,
+{
+    let parsed = parse::<S>(input)?;
+
+    Ok(Session {
+        queries: S::QUERIES,
+        answer: Box::new(move |query, args| S::query(&parsed, query, args)),
+    })
+}
+
+/// Like [`run`], but reads the input a line at a time, adding each record to the requested parts'
+/// totals before moving on to the next. Parsing and reading time is counted as parse time, and
+/// adding up each part's totals as that part's time.
+pub fn run_stream<S: Streaming>(reader: &mut dyn BufRead, parts: &[u8]) -> Result<Report> {
+    let mut parse = Measurement::default();
+    let mut totals: Vec<_> = parts
+        .iter()
+        .map(|&part| {
+            (
+                part,
+                S::Totals::default(),
+                Measurement::default(),
+                Vec::new(),
+            )
+        })
+        .collect();
+
+    let mut line = String::new();
+    let mut line_number = 0;
+
+    loop {
+        line.clear();
+        line_number += 1;
+
+        let (record, measurement) = Measurement::of(|| {
+            let read = reader.read_line(&mut line).map_err(|error| {
+                ParseError::new("", format!("couldn't read the input: {error}"))
+            })?;
+
+            if read == 0 {
+                return Ok(None);
+            }
+
+            let line = line.trim_end();
+            S::parse_line(line)
+                .map(Some)
+                .map_err(|error| error.locate(line).on_line(line_number).with_day(S::DAY))
+        });
+
+        parse.add(measurement);
+
+        let Some(record) = record? else {
+            break;
+        };
+
+        for (part, totals, measurement, notes) in &mut totals {
+            let (result, added) = Measurement::of(|| match part {
+                1 => S::add_1(totals, &record),
+                _ => S::add_2(totals, &record),
+            });
+
+            result.map_err(|error| error.on_line(line_number).with_day(S::DAY))?;
+            measurement.add(added);
+            notes.extend(explain::take());
+        }
+    }
+
+    let parts = totals
+        .into_iter()
+        .map(|(part, totals, mut measurement, mut notes)| {
+            let (answer, finished) = Measurement::of(|| S::finish(totals));
+            measurement.add(finished);
+            notes.extend(explain::take());
+
+            Ok(PartReport {
+                part,
+                answer: answer.map_err(|error| error.with_day(S::DAY))?.to_string(),
+                measurement,
+                notes,
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Report { parse, parts })
+}
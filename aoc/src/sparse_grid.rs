@@ -0,0 +1,205 @@
+//! A grid with no fixed size, for puzzles whose cells spread out in every direction.
+
+use crate::grid::{Cell, Direction, Grid};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub type Point = (i64, i64); // row, column, either of which can be negative
+
+/// Moves one step from `point` in the given direction. Unlike with a [`Grid`] there's no edge to
+/// fall off.
+pub fn step((row, col): Point, (row_offset, col_offset): Direction) -> Point {
+    (row + row_offset as i64, col + col_offset as i64)
+}
+
+/// The smallest rectangle holding every cell, with both corners inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn row_count(&self) -> usize {
+        (self.max.0 - self.min.0 + 1) as usize
+    }
+
+    pub fn col_count(&self) -> usize {
+        (self.max.1 - self.min.1 + 1) as usize
+    }
+
+    pub fn contains(&self, (row, col): Point) -> bool {
+        (self.min.0..=self.max.0).contains(&row) && (self.min.1..=self.max.1).contains(&col)
+    }
+}
+
+/// Cells keyed by their position, so only the cells that have been set take up any space.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// Copies the cells of a dense grid that `keep` accepts, with the top-left cell at `origin`.
+    pub fn from_dense(grid: &Grid<T>, origin: Point, mut keep: impl FnMut(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        let cells = grid
+            .iter()
+            .filter(|(_, value)| keep(value))
+            .map(|((row, col), value)| {
+                (
+                    (origin.0 + row as i64, origin.1 + col as i64),
+                    value.clone(),
+                )
+            })
+            .collect();
+
+        SparseGrid { cells }
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every cell that's been set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// The rectangle the cells cover, or `None` if there aren't any.
+    pub fn bounds(&self) -> Option<Bounds> {
+        let mut points = self.cells.keys();
+        let &first = points.next()?;
+
+        Some(points.fold(
+            Bounds {
+                min: first,
+                max: first,
+            },
+            |bounds, &(row, col)| Bounds {
+                min: (bounds.min.0.min(row), bounds.min.1.min(col)),
+                max: (bounds.max.0.max(row), bounds.max.1.max(col)),
+            },
+        ))
+    }
+
+    /// Copies the cells into a dense grid covering the bounds, filling the gaps with `empty`.
+    /// Returns the grid along with the point its top-left cell corresponds to.
+    pub fn to_dense(&self, empty: T) -> Option<(Grid<T>, Point)>
+    where
+        T: Clone,
+    {
+        let bounds = self.bounds()?;
+        let mut grid = Grid::filled(bounds.row_count(), bounds.col_count(), empty);
+
+        for (&(row, col), value) in &self.cells {
+            let cell: Cell = ((row - bounds.min.0) as usize, (col - bounds.min.1) as usize);
+            grid[cell] = value.clone();
+        }
+
+        Some((grid, bounds.min))
+    }
+
+    /// Draws the cells within the bounds, one row per line, with `empty` wherever there's no cell.
+    pub fn render(&self, empty: char) -> String
+    where
+        T: Display,
+    {
+        let Some(bounds) = self.bounds() else {
+            return String::new();
+        };
+
+        let mut text = String::new();
+
+        for row in bounds.min.0..=bounds.max.0 {
+            for col in bounds.min.1..=bounds.max.1 {
+                match self.get((row, col)) {
+                    Some(value) => text += &value.to_string(),
+                    None => text.push(empty),
+                }
+            }
+
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+#[test]
+fn sparse_grid() {
+    use crate::grid::{self, DIR_N, DIR_W};
+
+    let mut sparse = SparseGrid::new();
+    assert_eq!(sparse.bounds(), None);
+    assert_eq!(sparse.render('.'), "");
+
+    let mut point = (0, 0);
+
+    for direction in [DIR_N, DIR_N, DIR_W] {
+        sparse.insert(point, '#');
+        point = step(point, direction);
+    }
+
+    sparse.insert(point, 'S');
+    assert_eq!(point, (-2, -1));
+    assert_eq!(sparse.len(), 4);
+
+    let bounds = sparse.bounds().unwrap();
+    assert_eq!(
+        bounds,
+        Bounds {
+            min: (-2, -1),
+            max: (0, 0)
+        }
+    );
+    assert_eq!((bounds.row_count(), bounds.col_count()), (3, 2));
+    assert!(bounds.contains((-1, 0)) && !bounds.contains((1, 0)));
+    assert_eq!(sparse.render('.'), "S#\n.#\n.#\n");
+
+    let (dense, origin) = sparse.to_dense('.').unwrap();
+    assert_eq!(origin, (-2, -1));
+    assert_eq!(dense, grid::parse_grid("S#\n.#\n.#").unwrap());
+    assert_eq!(
+        SparseGrid::from_dense(&dense, origin, |&c| c != '.'),
+        sparse
+    );
+}
@@ -0,0 +1,208 @@
+//! Plays a day's simulation frame by frame, in the terminal or into a directory of text files.
+//!
+//! The terminal is put into raw mode while playing, so keys arrive as soon as they're pressed and
+//! the playback can be paused, stepped and sped up.
+
+use crate::terminal::RawMode;
+use aoc::render::{self, Frames, Palette, Scene};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+const MAX_FPS: u32 = 240;
+
+/// Where a day's frames go.
+pub enum Playback {
+    Terminal { fps: u32, palette: Palette },
+    Directory(PathBuf),
+}
+
+impl Playback {
+    pub fn play(&self, day: u8, frames: Frames) -> Result<(), String> {
+        match self {
+            Playback::Terminal { fps, palette } => play_in_terminal(day, frames, *fps, palette),
+            Playback::Directory(dir) => write_frames(dir, frames).map(|_| ()),
+        }
+    }
+}
+
+/// Writes each frame to `frame-00001.txt` and so on as [`render::text`], returning how many there
+/// were.
+pub fn write_frames(dir: &Path, frames: Frames) -> Result<usize, String> {
+    let error = |error: io::Error| format!("couldn't write frames to {}: {error}", dir.display());
+    std::fs::create_dir_all(dir).map_err(error)?;
+    let mut count = 0;
+
+    for (i, scene) in frames.enumerate() {
+        let path = dir.join(format!("frame-{:05}.txt", i + 1));
+        std::fs::write(path, render::text(&scene)).map_err(error)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+fn play_in_terminal(day: u8, frames: Frames, fps: u32, palette: &Palette) -> Result<(), String> {
+    let _raw_mode = RawMode::enter()?;
+    let _screen = AlternateScreen::enter();
+    let keys = read_keys();
+
+    let mut player = Player {
+        day,
+        frames,
+        frame: None,
+        frame_number: 0,
+        fps,
+        paused: false,
+        finished: false,
+    };
+
+    player.advance();
+
+    loop {
+        player.draw(palette).map_err(|error| error.to_string())?;
+
+        let key = if player.paused || player.finished {
+            match keys.recv() {
+                Ok(key) => Some(key),
+                Err(_) => return Ok(()), // no more keys, so nothing could unpause it
+            }
+        } else {
+            wait_for_key(&keys, Duration::from_secs_f64(1.0 / f64::from(player.fps)))
+        };
+
+        match key {
+            Some(b'q' | 0x03) => return Ok(()), // 0x03 is ctrl-C
+            Some(b' ') => player.paused = !player.paused,
+            Some(b'n') => {
+                player.paused = true;
+                player.advance();
+            }
+            Some(b'+' | b'=') => player.fps = (player.fps * 2).min(MAX_FPS),
+            Some(b'-') => player.fps = (player.fps / 2).max(1),
+            Some(_) => {}
+            None => player.advance(),
+        }
+    }
+}
+
+/// The key pressed within the delay, if any.
+fn wait_for_key(keys: &Receiver<u8>, delay: Duration) -> Option<u8> {
+    match keys.recv_timeout(delay) {
+        Ok(key) => Some(key),
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => {
+            thread::sleep(delay);
+            None
+        }
+    }
+}
+
+/// Sends each key as it's pressed. The thread is left blocked on stdin when playback ends, which is
+/// fine since nothing else reads it.
+fn read_keys() -> Receiver<u8> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut stdin = io::stdin().lock();
+        let mut buffer = [0; 16];
+
+        while let Ok(len @ 1..) = stdin.read(&mut buffer) {
+            if buffer[..len].iter().any(|&key| sender.send(key).is_err()) {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+struct Player {
+    day: u8,
+    frames: Frames,
+    frame: Option<Scene>,
+    frame_number: usize,
+    fps: u32,
+    paused: bool,
+    finished: bool,
+}
+
+impl Player {
+    fn advance(&mut self) {
+        match self.frames.next() {
+            Some(scene) => {
+                self.frame = Some(scene);
+                self.frame_number += 1;
+            }
+            None => self.finished = true,
+        }
+    }
+
+    fn draw(&self, palette: &Palette) -> io::Result<()> {
+        let state = match (self.finished, self.paused) {
+            (true, _) => "finished",
+            (false, true) => "paused",
+            (false, false) => "playing",
+        };
+
+        let status = format!(
+            "day {}  frame {}  {} fps  {state}    space pause  n step  +/- speed  q quit",
+            self.day, self.frame_number, self.fps
+        );
+
+        // Move to the top left and draw over the last frame, clearing what's left of the status
+        // line in case the last one was longer.
+        let mut screen = String::from("\x1b[H");
+
+        if let Some(scene) = &self.frame {
+            screen += &render::ansi(scene, palette);
+        }
+
+        screen += &status;
+        screen += "\x1b[K";
+
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(screen.as_bytes())?;
+        stdout.flush()
+    }
+}
+
+/// Draws the frames on the terminal's alternate screen with the cursor hidden, putting back what
+/// was there before when it's dropped.
+struct AlternateScreen;
+
+impl AlternateScreen {
+    fn enter() -> AlternateScreen {
+        print!("\x1b[?1049h\x1b[?25l\x1b[2J");
+        AlternateScreen
+    }
+}
+
+impl Drop for AlternateScreen {
+    fn drop(&mut self) {
+        print!("\x1b[0m\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+    }
+}
+
+#[test]
+fn frames() {
+    use aoc::grid::Grid;
+
+    let scenes = (0..3).map(|i| {
+        let tiles = Grid::filled(1, 3, '.');
+        Scene::new(tiles).with_layer("beam", [(0, i)])
+    });
+
+    let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+    assert_eq!(write_frames(&dir, Box::new(scenes)), Ok(3));
+
+    let frame = |n| std::fs::read_to_string(dir.join(format!("frame-0000{n}.txt"))).unwrap();
+    assert_eq!(frame(1), "...\n\nbeam:\n#..\n");
+    assert_eq!(frame(3), "...\n\nbeam:\n..#\n");
+    assert!(!dir.join("frame-00004.txt").exists());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,122 @@
+//! A small command line parser, enough for `aoc <command> <target> --option value`.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for --{name}"))?;
+
+                options.insert(name.to_string(), value);
+            } else {
+                positional.push(arg);
+            }
+        }
+
+        Ok(Args {
+            positional,
+            options,
+        })
+    }
+
+    pub fn command(&self) -> Option<&str> {
+        self.positional(0)
+    }
+
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(String::as_str)
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// Parses an option's value, falling back to `default` when it wasn't given.
+    pub fn parsed_option<T: FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.option(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("invalid value `{value}` for --{name}")),
+            None => Ok(default),
+        }
+    }
+
+    /// Fails if any option outside of `allowed` was given, so typos don't go unnoticed.
+    pub fn expect_options(&self, allowed: &[&str]) -> Result<(), String> {
+        match self
+            .options
+            .keys()
+            .find(|name| !allowed.contains(&name.as_str()))
+        {
+            Some(name) => Err(format!("unknown option --{name}")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses a selection like `14`, `1..5`, `1-5` or `1,3,7..9` into the numbers it covers.
+pub fn parse_selection(text: &str) -> Result<Vec<u8>, String> {
+    let parse_number = |number_str: &str| {
+        number_str
+            .trim()
+            .parse::<u8>()
+            .map_err(|_| format!("`{number_str}` is not a valid number"))
+    };
+
+    let mut result = Vec::new();
+
+    for item in text.split(',') {
+        let range = item.split_once("..").or_else(|| item.split_once('-'));
+
+        if let Some((start_str, end_str)) = range {
+            let start = parse_number(start_str)?;
+            let end = parse_number(end_str.trim_start_matches('='))?;
+            result.extend(start..=end);
+        } else {
+            result.push(parse_number(item)?);
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn parse_parts(text: Option<&str>) -> Result<Vec<u8>, String> {
+    match text {
+        None => Ok(vec![1, 2]),
+        Some("1") => Ok(vec![1]),
+        Some("2") => Ok(vec![2]),
+        Some(text) => Err(format!("part must be 1 or 2, not `{text}`")),
+    }
+}
+
+#[test]
+fn selection() {
+    assert_eq!(parse_selection("14"), Ok(vec![14]));
+    assert_eq!(parse_selection("1..4"), Ok(vec![1, 2, 3, 4]));
+    assert_eq!(parse_selection("1..=2"), Ok(vec![1, 2]));
+    assert_eq!(parse_selection("3-5"), Ok(vec![3, 4, 5]));
+    assert_eq!(parse_selection("1,3,7..9"), Ok(vec![1, 3, 7, 8, 9]));
+    assert!(parse_selection("one").is_err());
+
+    let args = Args::parse(["run", "14", "--part", "2"].map(String::from)).unwrap();
+    assert_eq!(args.command(), Some("run"));
+    assert_eq!(args.positional(1), Some("14"));
+    assert_eq!(args.option("part"), Some("2"));
+    assert_eq!(args.parsed_option("part", 1), Ok(2));
+    assert_eq!(args.parsed_option("jobs", 4), Ok(4));
+    assert!(args.expect_options(&["part"]).is_ok());
+    assert!(args.expect_options(&["input"]).is_err());
+}
@@ -0,0 +1,202 @@
+//! Runs days repeatedly to get stable timings for each step, and compares them against a baseline
+//! saved by an earlier run so that regressions stand out.
+
+use aoc::solution::Measurement;
+use days::Day;
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub allocations: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Measurement]) -> Stats {
+        let mut times: Vec<_> = samples.iter().map(|sample| sample.time).collect();
+        times.sort();
+
+        // Nearest-rank percentile: the smallest time that at least 95% of samples don't exceed.
+        let p95_index = (times.len() * 95).div_ceil(100).saturating_sub(1);
+
+        Stats {
+            min: times[0],
+            median: times[times.len() / 2],
+            p95: times[p95_index],
+            allocations: samples
+                .iter()
+                .map(|sample| sample.allocations)
+                .min()
+                .unwrap(),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub day: u8,
+    pub phase: &'static str,
+    pub stats: Stats,
+}
+
+/// Runs both parts of the day `warmup + iterations` times, keeping the measurements from all but
+/// the warmup runs.
+pub fn bench_day(
+    day: &Day,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> aoc::Result<Vec<BenchResult>> {
+    let mut samples: [Vec<Measurement>; 3] = Default::default();
+
+    for i in 0..(warmup + iterations) {
+        let report = (day.run)(input, &[1, 2])?;
+
+        if i < warmup {
+            continue;
+        }
+
+        samples[0].push(report.parse);
+
+        for part_report in report.parts {
+            samples[part_report.part as usize].push(part_report.measurement);
+        }
+    }
+
+    let results = PHASES
+        .iter()
+        .zip(samples)
+        .map(|(&phase, samples)| BenchResult {
+            day: day.number,
+            phase,
+            stats: Stats::from_samples(&samples),
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Stats from an earlier run, keyed by day and phase.
+pub struct Baseline(HashMap<(u8, String), Stats>);
+
+impl Baseline {
+    pub fn parse(text: &str) -> Result<Baseline, String> {
+        let mut stats = HashMap::new();
+
+        for (i, line) in text.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("invalid baseline entry on line {}: `{line}`", i + 1);
+            let fields: Vec<_> = line.split_whitespace().collect();
+
+            if fields.len() != 6 {
+                return Err(invalid());
+            }
+
+            let day = fields[0].parse().map_err(|_| invalid())?;
+            let nanos = |field: &str| field.parse().map(Duration::from_nanos);
+
+            let entry = Stats {
+                min: nanos(fields[2]).map_err(|_| invalid())?,
+                median: nanos(fields[3]).map_err(|_| invalid())?,
+                p95: nanos(fields[4]).map_err(|_| invalid())?,
+                allocations: fields[5].parse().map_err(|_| invalid())?,
+            };
+
+            stats.insert((day, fields[1].to_string()), entry);
+        }
+
+        Ok(Baseline(stats))
+    }
+
+    pub fn format(results: &[BenchResult]) -> String {
+        let mut text = String::from("# day phase min_ns median_ns p95_ns allocations\n");
+
+        for result in results {
+            let Stats {
+                min,
+                median,
+                p95,
+                allocations,
+            } = result.stats;
+
+            text += &format!(
+                "{} {} {} {} {} {allocations}\n",
+                result.day,
+                result.phase,
+                min.as_nanos(),
+                median.as_nanos(),
+                p95.as_nanos()
+            );
+        }
+
+        text
+    }
+
+    pub fn get(&self, day: u8, phase: &str) -> Option<&Stats> {
+        self.0.get(&(day, phase.to_string()))
+    }
+}
+
+pub struct Comparison {
+    pub time_change: f64,       // fraction, so 0.1 is 10% slower
+    pub allocation_change: f64, // fraction, so 0.1 is 10% more allocations
+    pub is_regression: bool,
+}
+
+/// Compares median times and allocation counts, flagging either growing by more than `threshold`
+/// (as a fraction). Medians are used because a single slow outlier shouldn't fail a comparison.
+pub fn compare(stats: &Stats, baseline: &Stats, threshold: f64) -> Comparison {
+    let change = |new: f64, old: f64| if old == 0.0 { 0.0 } else { new / old - 1.0 };
+    let time_change = change(stats.median.as_secs_f64(), baseline.median.as_secs_f64());
+    let allocation_change = change(stats.allocations as f64, baseline.allocations as f64);
+
+    Comparison {
+        time_change,
+        allocation_change,
+        is_regression: time_change > threshold || allocation_change > threshold,
+    }
+}
+
+#[test]
+fn stats() {
+    let samples: Vec<_> = (1..=20)
+        .rev()
+        .map(|millis| Measurement {
+            time: Duration::from_millis(millis),
+            allocations: 100 + millis as usize,
+        })
+        .collect();
+
+    let stats = Stats::from_samples(&samples);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(11));
+    assert_eq!(stats.p95, Duration::from_millis(19));
+    assert_eq!(stats.allocations, 101);
+
+    let results = [BenchResult {
+        day: 14,
+        phase: "part2",
+        stats,
+    }];
+
+    let baseline = Baseline::parse(&Baseline::format(&results)).unwrap();
+    assert_eq!(baseline.get(14, "part2"), Some(&stats));
+    assert_eq!(baseline.get(14, "part1"), None);
+    assert!(Baseline::parse("14 part2 1 2 3").is_err());
+
+    let slower = Stats {
+        median: Duration::from_millis(15),
+        ..stats
+    };
+
+    assert!(!compare(&stats, &stats, 0.1).is_regression);
+    assert!(compare(&slower, &stats, 0.1).is_regression);
+    assert!(!compare(&slower, &stats, 0.5).is_regression);
+}
@@ -0,0 +1,111 @@
+//! Settings for talking to the puzzle site, read from `aoc.conf` (or wherever `AOC_CONFIG` points):
+//!
+//! ```text
+//! # The value of the `session` cookie from a logged in browser.
+//! session = 53616c7465645f5f...
+//! base_url = https://adventofcode.com
+//! rate_limit = 5
+//! ```
+
+use crate::http::Client;
+use std::time::Duration;
+use std::{env, fs, io};
+
+pub const PATH: &str = "aoc.conf";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub rate_limit: Duration, // minimum time between requests
+    pub user_agent: String,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            rate_limit: Duration::from_secs(5),
+            user_agent: "github.com/mminer/aoc2023".to_string(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config file, falling back to the defaults if there isn't one.
+    pub fn load() -> Result<Config, String> {
+        let path = env::var("AOC_CONFIG").unwrap_or_else(|_| PATH.to_string());
+
+        match fs::read_to_string(&path) {
+            Ok(text) => Config::parse(&text).map_err(|error| format!("{path}: {error}")),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(error) => Err(format!("{path}: {error}")),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("invalid setting on line {}: `{line}`", i + 1);
+            let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+            let value = value.trim().to_string();
+
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value.trim_end_matches('/').to_string(),
+                "rate_limit" => {
+                    let seconds = value.parse().map_err(|_| invalid())?;
+                    config.rate_limit =
+                        Duration::try_from_secs_f64(seconds).map_err(|_| invalid())?;
+                }
+                "user_agent" => config.user_agent = value,
+                key => return Err(format!("unknown setting `{key}` on line {}", i + 1)),
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| {
+            format!("no session token; add `session = <token>` to {PATH} to talk to the site")
+        })
+    }
+
+    /// A client that's logged in with the session token.
+    pub fn client(&self) -> Result<Client, String> {
+        Ok(Client {
+            session: self.session()?.to_string(),
+            user_agent: self.user_agent.clone(),
+        })
+    }
+}
+
+#[test]
+fn config() {
+    let config = Config::parse(
+        "# comment\nsession = abc123\nbase_url = http://localhost:8080/\nrate_limit = 0.5\n",
+    )
+    .unwrap();
+
+    assert_eq!(config.session(), Ok("abc123"));
+    assert_eq!(config.base_url, "http://localhost:8080");
+    assert_eq!(config.rate_limit, Duration::from_millis(500));
+    assert_eq!(config.user_agent, Config::default().user_agent);
+
+    assert!(Config::default().session().is_err());
+    assert!(Config::parse("session abc123").is_err());
+    assert!(Config::parse("rate_limit = soon").is_err());
+    assert!(Config::parse("rate_limit = -1").is_err());
+    assert!(Config::parse("rate_limit = NaN").is_err());
+    assert!(Config::parse("rate_limit = inf").is_err());
+    assert!(Config::parse("year = 2023").is_err());
+}
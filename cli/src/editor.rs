@@ -0,0 +1,337 @@
+//! A line editor for the REPL, where the arrow keys move along the line and back through the lines
+//! entered before, and tab completes the first word. When stdin isn't a terminal, lines are read
+//! as they come with no prompt, so the REPL can be scripted.
+
+use crate::terminal::RawMode;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+
+pub struct Editor {
+    history: Vec<String>,
+    words: Vec<String>, // what tab completes the first word to
+    is_interactive: bool,
+}
+
+impl Editor {
+    pub fn new(words: Vec<String>) -> Editor {
+        Editor {
+            history: Vec::new(),
+            words,
+            is_interactive: io::stdin().is_terminal() && io::stdout().is_terminal(),
+        }
+    }
+
+    pub fn is_interactive(&self) -> bool {
+        self.is_interactive
+    }
+
+    /// The next line entered, or none once the input ends, as with ctrl-D on an empty line.
+    pub fn read_line(&mut self, prompt: &str) -> Result<Option<String>, String> {
+        if !self.is_interactive {
+            let mut line = String::new();
+            let read = io::stdin()
+                .lock()
+                .read_line(&mut line)
+                .map_err(|error| format!("couldn't read a line: {error}"))?;
+
+            return Ok((read > 0).then(|| line.trim_end().to_string()));
+        }
+
+        let _raw_mode = RawMode::enter()?;
+        let mut stdin = io::stdin().lock();
+        let mut line = Line::new(self.history.len());
+        draw(prompt, &line);
+
+        loop {
+            let key =
+                read_key(&mut stdin).map_err(|error| format!("couldn't read a key: {error}"))?;
+
+            match line.press(key, &self.history, &self.words) {
+                Outcome::Editing => {}
+                Outcome::Entered(text) => {
+                    println!();
+
+                    if !text.trim().is_empty() && self.history.last() != Some(&text) {
+                        self.history.push(text.clone());
+                    }
+
+                    return Ok(Some(text));
+                }
+                Outcome::Cleared => {
+                    println!("^C");
+                    line = Line::new(self.history.len());
+                }
+                Outcome::Ended => {
+                    println!();
+                    return Ok(None);
+                }
+                Outcome::Suggested(words) => println!("\n{}", words.join("  ")),
+            }
+
+            draw(prompt, &line);
+        }
+    }
+}
+
+/// Redraws the line over the last, leaving the cursor where it is in the line.
+fn draw(prompt: &str, line: &Line) {
+    let text: String = line.chars.iter().collect();
+    let mut screen = format!("\r{prompt}{text}\x1b[K");
+
+    if line.cursor < line.chars.len() {
+        screen += &format!("\x1b[{}D", line.chars.len() - line.cursor);
+    }
+
+    let mut stdout = io::stdout().lock();
+    let _ = stdout.write_all(screen.as_bytes());
+    let _ = stdout.flush();
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Key {
+    Char(char),
+    Backspace,
+    Enter,
+    Tab,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    Interrupt, // ctrl-C
+    Eof,       // ctrl-D
+    Other,
+}
+
+fn read_key(input: &mut impl Read) -> io::Result<Key> {
+    let Some(first) = read_byte(input)? else {
+        return Ok(Key::Eof);
+    };
+
+    let key = match first {
+        b'\r' | b'\n' => Key::Enter,
+        b'\t' => Key::Tab,
+        0x7f | 0x08 => Key::Backspace,
+        0x03 => Key::Interrupt,
+        0x04 => Key::Eof,
+        0x01 => Key::Home,
+        0x05 => Key::End,
+        0x1b => match (read_byte(input)?, read_byte(input)?) {
+            (Some(b'['), Some(b'A')) => Key::Up,
+            (Some(b'['), Some(b'B')) => Key::Down,
+            (Some(b'['), Some(b'C')) => Key::Right,
+            (Some(b'['), Some(b'D')) => Key::Left,
+            (Some(b'['), Some(b'H')) => Key::Home,
+            (Some(b'['), Some(b'F')) => Key::End,
+            _ => Key::Other,
+        },
+        byte if byte < 0x20 => Key::Other,
+        byte => {
+            // The rest of the character, if it's more than a byte of UTF-8.
+            let mut bytes = vec![byte];
+            let len = match byte {
+                0xf0.. => 4,
+                0xe0.. => 3,
+                0xc0.. => 2,
+                _ => 1,
+            };
+
+            while bytes.len() < len {
+                match read_byte(input)? {
+                    Some(byte) => bytes.push(byte),
+                    None => break,
+                }
+            }
+
+            match std::str::from_utf8(&bytes)
+                .ok()
+                .and_then(|c| c.chars().next())
+            {
+                Some(c) => Key::Char(c),
+                None => Key::Other,
+            }
+        }
+    };
+
+    Ok(key)
+}
+
+fn read_byte(input: &mut impl Read) -> io::Result<Option<u8>> {
+    let mut byte = [0];
+
+    match input.read(&mut byte)? {
+        0 => Ok(None),
+        _ => Ok(Some(byte[0])),
+    }
+}
+
+/// What pressing a key did to the line.
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Editing,
+    Entered(String),
+    Cleared,
+    Ended,
+    Suggested(Vec<String>), // more than one word the first word could be completed to
+}
+
+/// The line being edited.
+struct Line {
+    chars: Vec<char>,
+    cursor: usize,
+    history_index: usize, // the history's length while editing a new line
+    draft: Vec<char>,     // the new line, while looking back through the history
+}
+
+impl Line {
+    fn new(history_len: usize) -> Line {
+        Line {
+            chars: Vec::new(),
+            cursor: 0,
+            history_index: history_len,
+            draft: Vec::new(),
+        }
+    }
+
+    fn press(&mut self, key: Key, history: &[String], words: &[String]) -> Outcome {
+        match key {
+            Key::Char(c) => {
+                self.chars.insert(self.cursor, c);
+                self.cursor += 1;
+            }
+            Key::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.chars.remove(self.cursor);
+            }
+            Key::Left => self.cursor = self.cursor.saturating_sub(1),
+            Key::Right => self.cursor = (self.cursor + 1).min(self.chars.len()),
+            Key::Home => self.cursor = 0,
+            Key::End => self.cursor = self.chars.len(),
+            Key::Up if self.history_index > 0 => {
+                if self.history_index == history.len() {
+                    self.draft = self.chars.clone();
+                }
+
+                self.history_index -= 1;
+                self.replace(history[self.history_index].chars().collect());
+            }
+            Key::Down if self.history_index < history.len() => {
+                self.history_index += 1;
+
+                match history.get(self.history_index) {
+                    Some(line) => self.replace(line.chars().collect()),
+                    None => self.replace(self.draft.clone()),
+                }
+            }
+            Key::Tab => return self.complete(words),
+            Key::Enter => return Outcome::Entered(self.chars.iter().collect()),
+            Key::Interrupt => return Outcome::Cleared,
+            Key::Eof if self.chars.is_empty() => return Outcome::Ended,
+            _ => {}
+        }
+
+        Outcome::Editing
+    }
+
+    fn replace(&mut self, chars: Vec<char>) {
+        self.cursor = chars.len();
+        self.chars = chars;
+    }
+
+    /// Completes the first word as far as the words it could be agree, listing them if that
+    /// doesn't get any further.
+    fn complete(&mut self, words: &[String]) -> Outcome {
+        let typed: String = self.chars[..self.cursor].iter().collect();
+
+        if typed.contains(char::is_whitespace) {
+            return Outcome::Editing;
+        }
+
+        let matches: Vec<_> = words
+            .iter()
+            .filter(|word| word.starts_with(&typed))
+            .collect();
+
+        let completed = match &matches[..] {
+            [] => return Outcome::Editing,
+            [word] => format!("{word} "),
+            [first, rest @ ..] => first
+                .chars()
+                .enumerate()
+                .take_while(|&(i, c)| rest.iter().all(|word| word.chars().nth(i) == Some(c)))
+                .map(|(_, c)| c)
+                .collect(),
+        };
+
+        if completed.len() == typed.len() {
+            return Outcome::Suggested(matches.into_iter().cloned().collect());
+        }
+
+        let rest = self.chars.split_off(self.cursor);
+        self.chars = completed.chars().chain(rest).collect();
+        self.cursor = completed.chars().count();
+        Outcome::Editing
+    }
+}
+
+#[test]
+fn editing() {
+    let words = ["hash", "help", "box", "quit"].map(String::from);
+    let history = ["box 3", "hash rn=1"].map(String::from);
+    let mut line = Line::new(history.len());
+
+    let type_keys = |line: &mut Line, keys: &[Key]| {
+        let outcomes: Vec<_> = keys
+            .iter()
+            .map(|&key| line.press(key, &history, &words))
+            .collect();
+        let text: String = line.chars.iter().collect();
+        (text, outcomes.into_iter().last().unwrap())
+    };
+
+    let typed = "hsh".chars().map(Key::Char).collect::<Vec<_>>();
+    let fix = [
+        Key::Left,
+        Key::Left,
+        Key::Char('a'),
+        Key::End,
+        Key::Char(' '),
+    ];
+    assert_eq!(type_keys(&mut line, &typed).0, "hsh");
+    assert_eq!(type_keys(&mut line, &fix).0, "hash ");
+
+    // Up goes back through the history and down comes forward again to what was being typed.
+    assert_eq!(type_keys(&mut line, &[Key::Up]).0, "hash rn=1");
+    assert_eq!(type_keys(&mut line, &[Key::Up, Key::Up]).0, "box 3");
+    assert_eq!(type_keys(&mut line, &[Key::Down]).0, "hash rn=1");
+    assert_eq!(type_keys(&mut line, &[Key::Down, Key::Down]).0, "hash ");
+
+    let entered = type_keys(&mut line, &[Key::Backspace, Key::Enter]);
+    assert_eq!(entered.1, Outcome::Entered("hash".to_string()));
+
+    // Tab completes as far as the words agree, or lists them if they don't agree any further.
+    let mut line = Line::new(0);
+    assert_eq!(type_keys(&mut line, &[Key::Char('b'), Key::Tab]).0, "box ");
+
+    let mut line = Line::new(0);
+    let suggested = type_keys(&mut line, &[Key::Char('h'), Key::Tab]);
+    let words = vec!["hash".to_string(), "help".to_string()];
+    assert_eq!(suggested, ("h".to_string(), Outcome::Suggested(words)));
+    assert_eq!(type_keys(&mut line, &[Key::Char('a'), Key::Tab]).0, "hash ");
+
+    assert_eq!(type_keys(&mut Line::new(0), &[Key::Eof]).1, Outcome::Ended);
+
+    let mut keys: &[u8] = b"a\x1b[A\xc3\xa9\x7f\r";
+    let read: Vec<_> = (0..6).map(|_| read_key(&mut keys).unwrap()).collect();
+    assert_eq!(
+        read,
+        [
+            Key::Char('a'),
+            Key::Up,
+            Key::Char('é'),
+            Key::Backspace,
+            Key::Enter,
+            Key::Eof,
+        ]
+    );
+}
@@ -0,0 +1,167 @@
+//! Just enough HTTP to talk to the puzzle site: plain `http://` URLs are requested directly, which
+//! is what a local mock server uses, while `https://` URLs are handed to `curl`.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub struct Client {
+    pub session: String,
+    pub user_agent: String,
+}
+
+impl Client {
+    pub fn get(&self, url: &str) -> Result<Response, String> {
+        self.request(url, None)
+    }
+
+    /// Posts the fields as a URL-encoded form, like submitting an HTML form.
+    pub fn post_form(&self, url: &str, fields: &[(&str, &str)]) -> Result<Response, String> {
+        let form = fields
+            .iter()
+            .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        self.request(url, Some(&form))
+    }
+
+    fn request(&self, url: &str, form: Option<&str>) -> Result<Response, String> {
+        if url.starts_with("https://") {
+            self.curl(url, form)
+        } else {
+            self.plain_request(url, form)
+        }
+    }
+
+    fn plain_request(&self, url: &str, form: Option<&str>) -> Result<Response, String> {
+        let invalid = || format!("invalid URL `{url}`");
+        let rest = url.strip_prefix("http://").ok_or_else(invalid)?;
+
+        let (host, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/"),
+        };
+
+        let address = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{host}:80")
+        };
+
+        let mut stream = TcpStream::connect(&address)
+            .map_err(|error| format!("couldn't connect to {address}: {error}"))?;
+
+        // HTTP/1.0 so the server closes the connection after responding and never sends the body
+        // in chunks, which means the response is simply everything that comes back.
+        let mut request = format!(
+            "{} {path} HTTP/1.0\r\nHost: {host}\r\nUser-Agent: {}\r\nCookie: session={}\r\n",
+            if form.is_some() { "POST" } else { "GET" },
+            self.user_agent,
+            self.session
+        );
+
+        match form {
+            Some(form) => {
+                request += "Content-Type: application/x-www-form-urlencoded\r\n";
+                request += &format!("Content-Length: {}\r\n\r\n{form}", form.len());
+            }
+            None => request += "\r\n",
+        }
+
+        let mut response = Vec::new();
+
+        stream
+            .write_all(request.as_bytes())
+            .and_then(|_| stream.read_to_end(&mut response))
+            .map_err(|error| format!("request to {url} failed: {error}"))?;
+
+        let response = String::from_utf8_lossy(&response);
+        let malformed = || format!("malformed response from {url}");
+        let (head, body) = response.split_once("\r\n\r\n").ok_or_else(malformed)?;
+
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(malformed)?;
+
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+
+    fn curl(&self, url: &str, form: Option<&str>) -> Result<Response, String> {
+        let mut command = Command::new("curl");
+
+        // The status code is written after the body so it can be split off the end. The cookie is
+        // passed as config on stdin rather than as an argument, where any other user could see the
+        // session token in the process list.
+        command
+            .args(["--silent", "--show-error", "--write-out", "\n%{http_code}"])
+            .args(["--user-agent", &self.user_agent])
+            .args(["--config", "-"]);
+
+        if let Some(form) = form {
+            command.args(["--data", form]);
+        }
+
+        let mut child = command
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|error| format!("couldn't run curl: {error}"))?;
+
+        let session = self.session.replace('\\', "\\\\").replace('"', "\\\"");
+        let config = format!("cookie = \"session={session}\"\n");
+
+        // Dropping stdin once it's written closes it, so curl knows the config is all there.
+        let written = child
+            .stdin
+            .take()
+            .map(|mut stdin| stdin.write_all(config.as_bytes()));
+
+        let output = child
+            .wait_with_output()
+            .map_err(|error| format!("couldn't run curl: {error}"))?;
+
+        if let Some(Err(error)) = written {
+            return Err(format!("couldn't pass the session to curl: {error}"));
+        }
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("request to {url} failed: {}", error.trim()));
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = output.rsplit_once('\n').unwrap_or(("", &output));
+
+        Ok(Response {
+            status: status
+                .parse()
+                .map_err(|_| format!("malformed response from {url}"))?,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Percent-encodes everything but unreserved characters, as form values require.
+fn encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
@@ -0,0 +1,144 @@
+//! Puzzle inputs, cached under `inputs/YYYY/dayNN.txt` and downloaded the first time they're needed.
+
+use crate::config::Config;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fs, thread};
+
+pub const DIR: &str = "inputs";
+
+pub struct Inputs<'a> {
+    dir: PathBuf,
+    config: &'a Config,
+}
+
+impl Inputs<'_> {
+    pub fn new<'a>(dir: impl Into<PathBuf>, config: &'a Config) -> Inputs<'a> {
+        Inputs {
+            dir: dir.into(),
+            config,
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir
+            .join(days::YEAR.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// The day's input if it's already been downloaded.
+    pub fn cached(&self, day: u8) -> Option<String> {
+        fs::read_to_string(self.path(day)).ok()
+    }
+
+    /// The day's input, downloading it if it isn't cached yet. Once downloaded it's never fetched
+    /// again, since a day's input doesn't change.
+    pub fn load(&self, day: u8) -> Result<String, String> {
+        match self.cached(day) {
+            Some(input) => Ok(input),
+            None => self.fetch(day),
+        }
+    }
+
+    /// The path to the day's input, downloading it first if it isn't cached yet.
+    pub fn download(&self, day: u8) -> Result<PathBuf, String> {
+        let path = self.path(day);
+
+        if !path.exists() {
+            self.fetch(day)?;
+        }
+
+        Ok(path)
+    }
+
+    fn fetch(&self, day: u8) -> Result<String, String> {
+        let client = self.config.client()?;
+
+        let url = format!("{}/{}/day/{day}/input", self.config.base_url, days::YEAR);
+        throttle(&self.dir, self.config.rate_limit)?;
+        let response = client.get(&url)?;
+
+        if response.status != 200 {
+            let reason = response.body.lines().next().unwrap_or_default();
+            return Err(format!(
+                "couldn't download day {day}'s input ({}): {reason}",
+                response.status
+            ));
+        }
+
+        let path = self.path(day);
+        let save_error = |error| format!("couldn't save day {day}'s input to {path:?}: {error}");
+        fs::create_dir_all(path.parent().unwrap()).map_err(save_error)?;
+        fs::write(&path, &response.body).map_err(save_error)?;
+        Ok(response.body)
+    }
+}
+
+/// Waits until at least `interval` has passed since the last request. The time of the last request
+/// is kept in a file so the limit holds across separate runs of the binary too.
+pub fn throttle(dir: &Path, interval: Duration) -> Result<(), String> {
+    let path = dir.join(".last-request");
+    let now = || SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+    let last_request = fs::read_to_string(&path)
+        .ok()
+        .and_then(|text| text.trim().parse().ok())
+        .map(Duration::from_millis);
+
+    if let Some(wait) = last_request.and_then(|last| (last + interval).checked_sub(now())) {
+        eprintln!("waiting {:.1}s between requests", wait.as_secs_f64());
+        thread::sleep(wait);
+    }
+
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&path, now().as_millis().to_string()))
+        .map_err(|error| format!("couldn't write {path:?}: {error}"))
+}
+
+#[test]
+fn fetch_once() {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    // A mock server that answers a single request before shutting down, so any second request for
+    // the same input would fail.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+
+        while !request.ends_with(b"\r\n\r\n") {
+            let len = stream.read(&mut buffer).unwrap();
+            request.extend_from_slice(&buffer[..len]);
+        }
+
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 11\r\n\r\nTime: 7 15\n")
+            .unwrap();
+
+        String::from_utf8(request).unwrap()
+    });
+
+    let config = Config {
+        session: Some("abc123".to_string()),
+        base_url,
+        rate_limit: Duration::ZERO,
+        ..Config::default()
+    };
+
+    let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+    let inputs = Inputs::new(&dir, &config);
+    assert_eq!(inputs.cached(6), None);
+    assert_eq!(inputs.load(6).unwrap(), "Time: 7 15\n");
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2023/day/6/input HTTP/1.0\r\n"));
+    assert!(request.contains("\r\nCookie: session=abc123\r\n"));
+
+    assert_eq!(inputs.load(6).unwrap(), "Time: 7 15\n");
+    assert!(dir.join("2023/day06.txt").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
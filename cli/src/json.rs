@@ -0,0 +1,95 @@
+//! Just enough JSON writing for machine-readable output.
+
+use std::fmt::{self, Display, Formatter, Write};
+
+pub enum Value {
+    Null,
+    Number(u128),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(&'static str, Value)>), // fields in the order they're written
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Value {
+        Value::String(text.to_string())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Value {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Number(value) => write!(f, "{value}"),
+            Value::String(text) => write_string(f, text),
+            Value::Array(values) => {
+                f.write_char('[')?;
+
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+
+                    write!(f, "{value}")?;
+                }
+
+                f.write_char(']')
+            }
+            Value::Object(fields) => {
+                f.write_char('{')?;
+
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+
+                    write_string(f, name)?;
+                    write!(f, ":{value}")?;
+                }
+
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut Formatter, text: &str) -> fmt::Result {
+    f.write_char('"')?;
+
+    for c in text.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+
+    f.write_char('"')
+}
+
+#[test]
+fn json() {
+    let value = Value::Array(vec![
+        Value::Object(vec![
+            ("day", Value::Number(2)),
+            ("answer", "8".into()),
+            ("hash", None::<&str>.into()),
+        ]),
+        Value::from("line 1\n  \"purple\"\u{7}"),
+    ]);
+
+    assert_eq!(
+        value.to_string(),
+        r#"[{"day":2,"answer":"8","hash":null},"line 1\n  \"purple\"\u0007"]"#
+    );
+}
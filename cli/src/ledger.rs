@@ -0,0 +1,424 @@
+//! The answer ledger: a plain text file of answers known to be right or wrong for a day, part and
+//! input.
+//!
+//! Inputs differ between accounts, so entries are keyed by a hash of the input rather than just the
+//! day. Each line looks like:
+//!
+//! ```text
+//! 14 2 9c2f4b7e61d0a853 too-high 104
+//! 14 2 9c2f4b7e61d0a853 correct 64
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{self, BufRead, Read};
+
+pub const PATH: &str = "answers.txt";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Status {
+    fn parse(text: &str) -> Option<Status> {
+        match text {
+            "correct" => Some(Status::Correct),
+            "wrong" => Some(Status::Wrong),
+            "too-high" => Some(Status::TooHigh),
+            "too-low" => Some(Status::TooLow),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let text = match self {
+            Status::Correct => "correct",
+            Status::Wrong => "wrong",
+            Status::TooHigh => "too-high",
+            Status::TooLow => "too-low",
+        };
+
+        write!(f, "{text}")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub status: Status,
+    pub answer: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Ledger {
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Loads the ledger, treating a missing file as an empty ledger.
+    pub fn load(path: &str) -> Result<Ledger, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ledger::parse(&text).map_err(|error| format!("{path}: {error}")),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(error) => Err(format!("{path}: {error}")),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Ledger, String> {
+        let mut entries = Vec::new();
+
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("invalid entry on line {}: `{line}`", i + 1);
+            let mut fields = line.split_whitespace();
+            let mut next_field = || fields.next().ok_or_else(invalid);
+
+            let day = next_field()?.parse().map_err(|_| invalid())?;
+            let part = next_field()?.parse().map_err(|_| invalid())?;
+            let input_hash = next_field()?.to_string();
+            let status = Status::parse(next_field()?).ok_or_else(invalid)?;
+            let answer = next_field()?.to_string();
+
+            entries.push(Entry {
+                day,
+                part,
+                input_hash,
+                status,
+                answer,
+            });
+        }
+
+        Ok(Ledger { entries })
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn correct_answer(&self, day: u8, part: u8, input_hash: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| {
+                entry.day == day
+                    && entry.part == part
+                    && entry.input_hash == input_hash
+                    && entry.status == Status::Correct
+            })
+            .map(|entry| entry.answer.as_str())
+    }
+
+    /// Explains why submitting `answer` would be pointless, if the ledger already knows it's wrong:
+    /// either it was rejected before, or it's no lower than an answer that was too high (or no
+    /// higher than one that was too low), or the part has already been solved.
+    pub fn check_submission(
+        &self,
+        day: u8,
+        part: u8,
+        input_hash: &str,
+        answer: &str,
+    ) -> Result<(), String> {
+        let number = answer.parse::<i128>().ok();
+
+        for entry in self.entries.iter().filter(|entry| {
+            entry.day == day && entry.part == part && entry.input_hash == input_hash
+        }) {
+            let bound = entry.answer.parse::<i128>().ok();
+            let is_beyond = |beyond: fn(&i128, &i128) -> bool| match (number, bound) {
+                (Some(number), Some(bound)) => beyond(&number, &bound),
+                _ => false,
+            };
+
+            let reason = match entry.status {
+                Status::Correct => format!("the answer {} was already accepted", entry.answer),
+                _ if entry.answer == answer => format!("{answer} was already rejected"),
+                Status::TooHigh if is_beyond(i128::ge) => {
+                    format!("{answer} isn't below {}, which was too high", entry.answer)
+                }
+                Status::TooLow if is_beyond(i128::le) => {
+                    format!("{answer} isn't above {}, which was too low", entry.answer)
+                }
+                _ => continue,
+            };
+
+            return Err(format!("not submitting day {day} part {part}: {reason}"));
+        }
+
+        Ok(())
+    }
+
+    /// Records a rejected answer, keeping it so the same answer isn't submitted twice.
+    pub fn record_rejected(
+        &mut self,
+        day: u8,
+        part: u8,
+        input_hash: &str,
+        status: Status,
+        answer: &str,
+    ) {
+        self.entries.push(Entry {
+            day,
+            part,
+            input_hash: input_hash.to_string(),
+            status,
+            answer: answer.to_string(),
+        });
+
+        self.entries.sort_by_key(|entry| (entry.day, entry.part));
+    }
+
+    /// Records the correct answer for a day, part and input. A different answer that's already
+    /// recorded is left alone and returned as an error, since one of them must be wrong.
+    pub fn record_correct(
+        &mut self,
+        day: u8,
+        part: u8,
+        input_hash: &str,
+        answer: &str,
+    ) -> Result<(), String> {
+        match self.correct_answer(day, part, input_hash) {
+            Some(recorded) if recorded == answer => Ok(()),
+            Some(recorded) => Err(format!(
+                "day {day} part {part} already has {recorded} recorded as correct, not {answer}"
+            )),
+            None => {
+                self.entries.push(Entry {
+                    day,
+                    part,
+                    input_hash: input_hash.to_string(),
+                    status: Status::Correct,
+                    answer: answer.to_string(),
+                });
+
+                self.entries.sort_by_key(|entry| (entry.day, entry.part));
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for Ledger {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "# day part input status answer")?;
+
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{} {} {} {} {}",
+                entry.day, entry.part, entry.input_hash, entry.status, entry.answer
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A short fingerprint of the input, ignoring differences in line endings and trailing whitespace.
+/// This uses 64-bit FNV-1a, which unlike the standard library's hasher is stable across releases.
+pub fn hash_input(input: &str) -> String {
+    let mut hasher = InputHasher::default();
+    hasher.update(input.as_bytes());
+    hasher.finish()
+}
+
+/// Works out [`hash_input`] a piece at a time, for inputs that are never held in memory all at
+/// once. Trailing whitespace can only be told apart from the rest once a line or the input ends,
+/// so it's held back until then.
+pub struct InputHasher {
+    hash: u64,
+    in_line: bool,       // whether the current line has anything but whitespace yet
+    whitespace: Vec<u8>, // whitespace since the last character of the current line
+    blank_lines: usize,  // blank lines since the last line with anything on it
+}
+
+impl Default for InputHasher {
+    fn default() -> InputHasher {
+        InputHasher {
+            hash: 0xcbf29ce484222325,
+            in_line: false,
+            whitespace: Vec::new(),
+            blank_lines: 0,
+        }
+    }
+}
+
+impl InputHasher {
+    pub fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            match byte {
+                b'\n' if self.in_line => {
+                    self.write(b'\n');
+                    self.in_line = false;
+                    self.whitespace.clear();
+                }
+                b'\n' => {
+                    self.blank_lines += 1;
+                    self.whitespace.clear();
+                }
+                _ if byte.is_ascii_whitespace() => self.whitespace.push(byte),
+                _ => {
+                    // Whitespace and blank lines only count once something follows them.
+                    if !self.in_line {
+                        for _ in 0..self.blank_lines {
+                            self.write(b'\n');
+                        }
+
+                        self.blank_lines = 0;
+                        self.in_line = true;
+                    }
+
+                    for i in 0..self.whitespace.len() {
+                        self.write(self.whitespace[i]);
+                    }
+
+                    self.whitespace.clear();
+                    self.write(byte);
+                }
+            }
+        }
+    }
+
+    pub fn finish(mut self) -> String {
+        if self.in_line {
+            self.write(b'\n');
+        }
+
+        format!("{:016x}", self.hash)
+    }
+
+    fn write(&mut self, byte: u8) {
+        self.hash ^= byte as u64;
+        self.hash = self.hash.wrapping_mul(0x100000001b3);
+    }
+}
+
+/// Hashes the input as it's read, so a streamed input's hash is known once it's been solved.
+pub struct HashingReader<R> {
+    inner: R,
+    pub hasher: InputHasher,
+}
+
+impl<R: BufRead> HashingReader<R> {
+    pub fn new(inner: R) -> HashingReader<R> {
+        HashingReader {
+            inner,
+            hasher: InputHasher::default(),
+        }
+    }
+}
+
+impl<R: BufRead> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+impl<R: BufRead> BufRead for HashingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amount: usize) {
+        // The consumed bytes are still at the front of the buffer until the inner reader's told.
+        if let Ok(buffer) = self.inner.fill_buf() {
+            self.hasher.update(&buffer[..amount.min(buffer.len())]);
+        }
+
+        self.inner.consume(amount);
+    }
+}
+
+// One test per correct answer in the ledger is generated by the build script, so a refactor that
+// changes any recorded answer fails `cargo test`. Both of these go unused while the ledger is empty.
+#[cfg(test)]
+#[allow(unused_macros)]
+macro_rules! ledger_test {
+    ($name:ident, $day:literal, $part:literal, $input_hash:literal, $answer:literal) => {
+        #[test]
+        fn $name() {
+            check_recorded_answer($day, $part, $input_hash, $answer);
+        }
+    };
+}
+
+#[cfg(test)]
+include!(concat!(env!("OUT_DIR"), "/ledger_tests.rs"));
+
+#[cfg(test)]
+#[allow(dead_code)]
+fn check_recorded_answer(day: u8, part: u8, input_hash: &str, answer: &str) {
+    let path = format!(
+        "{}/../inputs/{}/day{day:02}.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        days::YEAR
+    );
+
+    // Recorded answers for someone else's input can't be checked here.
+    let input = match fs::read_to_string(&path) {
+        Ok(input) if hash_input(&input) == input_hash => input,
+        _ => {
+            eprintln!("skipping day {day} part {part}: no input with hash {input_hash}");
+            return;
+        }
+    };
+
+    let report = (days::find_day(day).unwrap().run)(&input, &[part]).unwrap();
+    assert_eq!(
+        report.parts[0].answer, answer,
+        "day {day} part {part} no longer matches the ledger"
+    );
+}
+
+#[test]
+fn ledger() {
+    let mut ledger = Ledger::parse("# comment\n14 2 00ff correct 64\n").unwrap();
+    assert_eq!(ledger.correct_answer(14, 2, "00ff"), Some("64"));
+    assert_eq!(ledger.correct_answer(14, 2, "ff00"), None);
+
+    assert!(ledger.record_correct(14, 2, "00ff", "64").is_ok());
+    assert!(ledger.record_correct(14, 2, "00ff", "65").is_err());
+    assert!(ledger.record_correct(1, 1, "00ff", "142").is_ok());
+
+    assert_eq!(
+        ledger.to_string(),
+        "# day part input status answer\n1 1 00ff correct 142\n14 2 00ff correct 64\n"
+    );
+
+    ledger.record_rejected(14, 1, "00ff", Status::TooHigh, "200");
+    ledger.record_rejected(14, 1, "00ff", Status::TooLow, "100");
+    ledger.record_rejected(14, 1, "00ff", Status::Wrong, "150");
+    assert!(ledger.check_submission(14, 1, "00ff", "140").is_ok());
+    assert!(ledger.check_submission(14, 1, "00ff", "150").is_err());
+    assert!(ledger.check_submission(14, 1, "00ff", "200").is_err());
+    assert!(ledger.check_submission(14, 1, "00ff", "99").is_err());
+    assert!(ledger.check_submission(14, 1, "ff00", "99").is_ok());
+    assert!(ledger.check_submission(14, 2, "00ff", "63").is_err());
+
+    let reloaded = Ledger::parse(&ledger.to_string()).unwrap();
+    assert!(reloaded.check_submission(14, 1, "00ff", "200").is_err());
+
+    assert!(Ledger::parse("14 2 00ff maybe 64").is_err());
+    assert!(Ledger::parse("14 2 00ff").is_err());
+
+    assert_eq!(hash_input("a\nb\n"), hash_input("a\r\nb  \r\n\n"));
+    assert_ne!(hash_input("a\nb\n"), hash_input("a\nc\n"));
+    assert_ne!(hash_input("a\n\nb"), hash_input("a\nb"));
+
+    let input = "  a \n\n b\t\r\n\n\n";
+    let mut reader = HashingReader::new(io::BufReader::with_capacity(3, input.as_bytes()));
+    let lines: Vec<_> = reader.by_ref().lines().collect::<io::Result<_>>().unwrap();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(reader.hasher.finish(), hash_input(input));
+}
@@ -0,0 +1,1030 @@
+//! The `aoc` command line runner for every registered day.
+
+mod animate;
+mod args;
+mod bench;
+mod config;
+mod editor;
+mod http;
+mod inputs;
+mod json;
+mod ledger;
+mod scaffold;
+mod submit;
+mod table;
+mod terminal;
+
+use animate::Playback;
+use aoc::alloc::CountingAllocator;
+use aoc::generate::{self, Rng};
+use aoc::pool;
+use aoc::render;
+use aoc::solution::{Measurement, PartReport, Query, Report};
+use args::Args;
+use bench::Baseline;
+use config::Config;
+use days::Day;
+use editor::Editor;
+use inputs::Inputs;
+use json::Value;
+use ledger::{HashingReader, Ledger};
+use std::any::Any;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fs, iter, process};
+use submit::Outcome;
+use table::{Align, Table};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+  run <days>        Solve days given as `14`, `1..5`, `1,3,7` or `all`
+  fetch <days>      Download the days' inputs if they aren't already cached
+  bench <days>      Time each day's parsing and parts over many runs
+  record <days>     Save the days' answers to the ledger once they're known to be right
+  verify [days]     Check answers against the ledger (defaults to all days)
+  submit <day>      Submit a day's answer to the site and record the outcome in the ledger
+  new <day>         Create a new day from the template and register it
+  generate <day>    Print a random input for the day, for stress testing
+  cross-check [days]
+                    Check days against their slower reference implementations on
+                    generated inputs (defaults to all days that have one)
+  repl <day>        Load a day's input (5, 8 or 15) and ask it questions, like
+                    where a seed ends up; `help` lists what each day answers
+
+Run options:
+  --part <1|2>      Only solve the given part
+  --input <path>    Read a single day's input from a file, or `-` for stdin
+                    (defaults to inputs/YYYY/dayNN.txt, downloading it if needed)
+  --format <text|json>
+                    Print a table (the default) or a JSON array with each part's
+                    answer, timings, input hash and status
+  --jobs <n>        How many threads to solve on at once, shared by the days and the
+                    work within them (defaults to one per core); answers are
+                    always reported in order
+  --explain <text|json>
+                    Instead of the results, print the notes each part leaves on how
+                    it got its answer (like each line's value in day 1) as a
+                    readable report or JSON
+  --render <path>   Also draw a single grid day (10, 11, 14 or 16) to a .png, .ppm
+                    or .svg image, with what it works out highlighted
+  --cell-size <px>  How big each tile is drawn (default 8)
+  --animate <dir|->  Play a single day's simulation (14 or 16) frame by frame in
+                    the terminal with `-`, or write each frame to a text file in
+                    the directory; while playing, space pauses, n steps, + and -
+                    change the speed and q quits
+  --fps <n>         Frames a second when playing in the terminal (default 10)
+  --palette <colours>
+                    Colours to use instead of the defaults, by tile or layer, like
+                    `path=ff8800,#=333333`
+
+Record options:
+  --part <1|2>      Only record the given part
+  --input <path>    As for run
+
+Submit options:
+  --part <1|2>      The part to submit (defaults to the first one not yet solved)
+  --input <path>    As for run
+
+Generate options:
+  --seed <n>        Seed for the random numbers; the same seed gives the same input
+                    (default 0)
+  --size <n>        Roughly how many lines or rows to generate (default 100)
+  --density <0..1>  How densely packed the day's features are, for days with any
+
+Repl options:
+  --input <path>    Read the input from a file (defaults to inputs/YYYY/dayNN.txt)
+
+Cross-check options:
+  --seeds <n>       How many inputs to generate, from seeds 0 up (default 100)
+  --size <n>        As for generate
+  --density <0..1>  As for generate
+
+Bench options:
+  --iterations <n>  Runs to measure (default 10)
+  --warmup <n>      Runs to discard before measuring (default 2)
+  --save <path>     Write the results to a baseline file
+  --baseline <path> Compare against a baseline file, failing on regressions
+  --threshold <%>   How much slower or allocation-heavy counts as a regression
+                    (default 10)
+  --jobs <n>        As for run; days are always timed one at a time, but some
+                    split their own work across threads
+";
+
+fn main() {
+    if let Err(error) = try_main() {
+        eprintln!("error: {error}");
+        process::exit(1);
+    }
+}
+
+fn try_main() -> Result<()> {
+    let args = Args::parse(env::args().skip(1))?;
+
+    match args.command() {
+        Some("run") => run(&args),
+        Some("fetch") => fetch(&args),
+        Some("bench") => bench(&args),
+        Some("record") => record(&args),
+        Some("verify") => verify(&args),
+        Some("submit") => submit(&args),
+        Some("new") => new(&args),
+        Some("generate") => generate(&args),
+        Some("cross-check") => cross_check(&args),
+        Some("repl") => repl(&args),
+        Some(command) => Err(format!("unknown command `{command}`\n\n{USAGE}").into()),
+        None => {
+            print!("{USAGE}");
+            Ok(())
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<()> {
+    args.expect_options(&[
+        "part",
+        "input",
+        "format",
+        "render",
+        "cell-size",
+        "palette",
+        "animate",
+        "fps",
+        "explain",
+        "jobs",
+    ])?;
+
+    let days = select_days(args.positional(1).ok_or("missing days to run")?)?;
+    let parts = args::parse_parts(args.option("part"))?;
+    let input_path = args.option("input");
+
+    let output = match (args.option("format"), args.option("explain")) {
+        (None | Some("text"), None) => Output::Table,
+        (Some("json"), None) => Output::Json,
+        (None, Some("text")) => Output::Explanation,
+        (None, Some("json")) => Output::ExplanationJson,
+        (Some(_), Some(_)) => return Err("--explain can't be used with --format".into()),
+        (Some(format), None) | (None, Some(format)) => {
+            return Err(format!("unknown format `{format}`, expected text or json").into());
+        }
+    };
+
+    let is_json = matches!(output, Output::Json | Output::ExplanationJson);
+
+    if matches!(output, Output::Explanation | Output::ExplanationJson) {
+        aoc::explain::enable();
+    }
+
+    if input_path.is_some() && days.len() > 1 {
+        return Err("--input can only be used when running a single day".into());
+    }
+
+    let drawing = args
+        .option("render")
+        .map(|path| Drawing::new(args, path))
+        .transpose()?;
+
+    let animation = args
+        .option("animate")
+        .map(|target| playback(args, target))
+        .transpose()?;
+
+    let is_animated = animation.is_some();
+
+    if drawing.is_none() && args.option("cell-size").is_some() {
+        return Err("--cell-size only applies with --render".into());
+    }
+
+    if drawing.is_none() && !is_animated && args.option("palette").is_some() {
+        return Err("--palette only applies with --render or --animate".into());
+    }
+
+    if !is_animated && args.option("fps").is_some() {
+        return Err("--fps only applies with --animate".into());
+    }
+
+    if matches!(animation, Some(Playback::Terminal { .. })) && input_path == Some("-") {
+        return Err("--animate - reads keys from stdin, so the input can't come from it".into());
+    }
+
+    match (&drawing, &days[..]) {
+        (Some(_), [day]) if day.render.is_none() => {
+            return Err(format!("day {} has nothing to render", day.number).into());
+        }
+        (Some(_), [_]) | (None, _) => {}
+        (Some(_), _) => return Err("--render can only be used when running a single day".into()),
+    }
+
+    match (&animation, &days[..]) {
+        (Some(_), [day]) if day.animate.is_none() => {
+            return Err(format!("day {} has nothing to animate", day.number).into());
+        }
+        (Some(_), [_]) | (None, _) => {}
+        (Some(_), _) => return Err("--animate can only be used when running a single day".into()),
+    }
+
+    set_jobs(args)?;
+
+    let config = Config::load()?;
+    let inputs = Inputs::new(inputs::DIR, &config);
+
+    // Inputs that aren't cached yet are downloaded one at a time, to keep to the site's rate limit,
+    // then the days are solved side by side and reported in order.
+    let downloads: Vec<_> = days
+        .iter()
+        .map(|day| match input_path {
+            Some(_) => Ok(()),
+            None => inputs.download(day.number).map(|_| ()),
+        })
+        .collect();
+
+    let visuals = (drawing.as_ref(), animation.as_ref());
+    let tasks: Vec<_> = days.iter().zip(downloads).collect();
+
+    // A day that panics is reported as failing like any other, rather than taking the rest of the
+    // days' results down with it.
+    let outcomes = pool::map(&tasks, |(day, download)| {
+        download.clone()?;
+
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            run_day(&inputs, day, input_path, &parts, visuals).map_err(|error| error.to_string())
+        }));
+
+        outcome.unwrap_or_else(|panic| Err(panic_message(day, panic.as_ref())))
+    });
+
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Answer", Align::Left),
+        ("Parse", Align::Right),
+        ("Solve", Align::Right),
+    ]);
+
+    let mut results = Vec::new(); // for JSON
+    let mut explanation = String::new();
+    let mut total_time = Duration::ZERO;
+    let mut failed = false;
+
+    for (day, outcome) in days.into_iter().zip(outcomes) {
+        let (report, input_hash) = match outcome {
+            Ok(result) => result,
+            Err(error) => {
+                if is_json {
+                    results.extend(parts.iter().map(|&part| json_error(day, part, &error)));
+                } else {
+                    eprintln!("error: {error}");
+                }
+
+                failed = true;
+                continue;
+            }
+        };
+
+        total_time += report.parse.time;
+
+        for (i, part_report) in report.parts.into_iter().enumerate() {
+            total_time += part_report.measurement.time;
+
+            match output {
+                Output::Table => {}
+                Output::Json => {
+                    results.push(json_result(day, &part_report, &report.parse, &input_hash));
+                }
+                Output::Explanation => explanation += &explain(day, &part_report),
+                Output::ExplanationJson => results.push(json_explanation(day, &part_report)),
+            }
+
+            // Both parts share the parsed input, so its time is only shown once.
+            let parse_time = if i == 0 {
+                table::format_duration(report.parse.time)
+            } else {
+                String::new()
+            };
+
+            table.push(vec![
+                day.number.to_string(),
+                part_report.part.to_string(),
+                part_report.answer,
+                parse_time,
+                table::format_duration(part_report.measurement.time),
+            ]);
+        }
+    }
+
+    if is_json {
+        println!("{}", Value::Array(results));
+    } else if output == Output::Explanation {
+        print!("{explanation}");
+    } else {
+        print!("{table}");
+        println!("Total: {}", table::format_duration(total_time));
+    }
+
+    if failed {
+        return Err("some days could not be run".into());
+    }
+
+    Ok(())
+}
+
+/// What `run` prints.
+#[derive(Clone, Copy, PartialEq)]
+enum Output {
+    Table,
+    Json,
+    Explanation,
+    ExplanationJson,
+}
+
+/// A part's answer followed by the notes it left on the way, for `run --explain text`.
+fn explain(day: &Day, report: &PartReport) -> String {
+    let mut text = format!(
+        "Day {}, part {}: {}\n",
+        day.number, report.part, report.answer
+    );
+
+    if report.notes.is_empty() {
+        text += "  (nothing noted)\n";
+    }
+
+    for note in &report.notes {
+        text += &format!("  {note}\n");
+    }
+
+    text + "\n"
+}
+
+/// A part's answer with the notes it left on the way, for `run --explain json`. Each note is an
+/// object of the facts in it.
+fn json_explanation(day: &Day, report: &PartReport) -> Value {
+    let notes = report
+        .notes
+        .iter()
+        .map(|note| {
+            let facts = note
+                .facts
+                .iter()
+                .map(|(name, value)| (*name, value.as_str().into()))
+                .collect();
+
+            Value::Object(facts)
+        })
+        .collect();
+
+    Value::Object(vec![
+        ("day", Value::Number(day.number.into())),
+        ("part", Value::Number(report.part.into())),
+        ("answer", report.answer.as_str().into()),
+        ("notes", Value::Array(notes)),
+    ])
+}
+
+/// A successfully solved part for `run --format json`. Both parts share the parse time.
+fn json_result(day: &Day, report: &PartReport, parse: &Measurement, input_hash: &str) -> Value {
+    Value::Object(vec![
+        ("day", Value::Number(day.number.into())),
+        ("part", Value::Number(report.part.into())),
+        ("status", "ok".into()),
+        ("answer", report.answer.as_str().into()),
+        ("parse_ns", Value::Number(parse.time.as_nanos())),
+        (
+            "solve_ns",
+            Value::Number(report.measurement.time.as_nanos()),
+        ),
+        ("input_hash", input_hash.into()),
+        ("error", Value::Null),
+    ])
+}
+
+/// A part that couldn't be solved for `run --format json`, with the same fields as a solved one.
+fn json_error(day: &Day, part: u8, error: &str) -> Value {
+    Value::Object(vec![
+        ("day", Value::Number(day.number.into())),
+        ("part", Value::Number(part.into())),
+        ("status", "error".into()),
+        ("answer", Value::Null),
+        ("parse_ns", Value::Null),
+        ("solve_ns", Value::Null),
+        ("input_hash", Value::Null),
+        ("error", error.into()),
+    ])
+}
+
+fn fetch(args: &Args) -> Result<()> {
+    args.expect_options(&[])?;
+
+    let days = select_days(args.positional(1).ok_or("missing days to fetch")?)?;
+    let config = Config::load()?;
+    let inputs = Inputs::new(inputs::DIR, &config);
+
+    for day in days {
+        if inputs.cached(day.number).is_none() {
+            inputs.load(day.number)?;
+            println!("Downloaded {}", inputs.path(day.number).display());
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(args: &Args) -> Result<()> {
+    args.expect_options(&[
+        "iterations",
+        "warmup",
+        "save",
+        "baseline",
+        "threshold",
+        "jobs",
+    ])?;
+
+    let days = select_days(args.positional(1).ok_or("missing days to benchmark")?)?;
+    let iterations = args.parsed_option("iterations", 10)?;
+    let warmup = args.parsed_option("warmup", 2)?;
+    let threshold = args.parsed_option("threshold", 10.0)? / 100.0;
+
+    if iterations == 0 {
+        return Err("--iterations must be at least 1".into());
+    }
+
+    set_jobs(args)?;
+
+    let config = Config::load()?;
+    let inputs = Inputs::new(inputs::DIR, &config);
+
+    let baseline = match args.option("baseline") {
+        Some(path) => Some(Baseline::parse(&fs::read_to_string(path)?)?),
+        None => None,
+    };
+
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Phase", Align::Left),
+        ("Min", Align::Right),
+        ("Median", Align::Right),
+        ("p95", Align::Right),
+        ("Allocs", Align::Right),
+        ("Baseline", Align::Left),
+    ]);
+
+    let mut results = Vec::new();
+    let mut regression_count = 0;
+
+    for day in days {
+        let input = read_input(&inputs, day, None)?;
+        let day_results = bench::bench_day(day, &input, warmup, iterations)?;
+
+        for result in &day_results {
+            let comparison = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get(result.day, result.phase))
+                .map(|baseline_stats| bench::compare(&result.stats, baseline_stats, threshold));
+
+            let comparison_str = match comparison {
+                Some(comparison) => {
+                    let mut text = format!(
+                        "{:+.1}% time, {:+.1}% allocs",
+                        comparison.time_change * 100.0,
+                        comparison.allocation_change * 100.0
+                    );
+
+                    if comparison.is_regression {
+                        regression_count += 1;
+                        text += "  REGRESSION";
+                    }
+
+                    text
+                }
+                None if baseline.is_some() => "new".to_string(),
+                None => String::new(),
+            };
+
+            table.push(vec![
+                result.day.to_string(),
+                result.phase.to_string(),
+                table::format_duration(result.stats.min),
+                table::format_duration(result.stats.median),
+                table::format_duration(result.stats.p95),
+                result.stats.allocations.to_string(),
+                comparison_str,
+            ]);
+        }
+
+        results.extend(day_results);
+    }
+
+    print!("{table}");
+
+    if let Some(path) = args.option("save") {
+        fs::write(path, Baseline::format(&results))?;
+        println!("Saved baseline to {path}");
+    }
+
+    if regression_count > 0 {
+        return Err(format!("{regression_count} regressions against the baseline").into());
+    }
+
+    Ok(())
+}
+
+fn record(args: &Args) -> Result<()> {
+    args.expect_options(&["part", "input"])?;
+
+    let days = select_days(args.positional(1).ok_or("missing days to record")?)?;
+    let parts = args::parse_parts(args.option("part"))?;
+    let input_path = args.option("input");
+
+    if input_path.is_some() && days.len() > 1 {
+        return Err("--input can only be used when recording a single day".into());
+    }
+
+    let config = Config::load()?;
+    let inputs = Inputs::new(inputs::DIR, &config);
+
+    let mut ledger = Ledger::load(ledger::PATH)?;
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Answer", Align::Left),
+        ("Input", Align::Left),
+    ]);
+
+    for day in days {
+        let input = read_input(&inputs, day, input_path)?;
+        let input_hash = ledger::hash_input(&input);
+        let report = (day.run)(&input, &parts)?;
+
+        for part_report in report.parts {
+            ledger.record_correct(
+                day.number,
+                part_report.part,
+                &input_hash,
+                &part_report.answer,
+            )?;
+
+            table.push(vec![
+                day.number.to_string(),
+                part_report.part.to_string(),
+                part_report.answer,
+                input_hash.clone(),
+            ]);
+        }
+    }
+
+    ledger.save(ledger::PATH)?;
+    print!("{table}");
+    println!("Recorded in {}", ledger::PATH);
+    Ok(())
+}
+
+fn verify(args: &Args) -> Result<()> {
+    args.expect_options(&[])?;
+
+    let days = select_days(args.positional(1).unwrap_or("all"))?;
+    let ledger = Ledger::load(ledger::PATH)?;
+    let config = Config::load()?;
+    let inputs = Inputs::new(inputs::DIR, &config);
+
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Answer", Align::Left),
+        ("Recorded", Align::Left),
+        ("Status", Align::Left),
+    ]);
+
+    let mut drift_count = 0;
+
+    for day in days {
+        // Verifying never downloads anything; a day without an input just has nothing to check.
+        let Some(input) = inputs.cached(day.number) else {
+            table.push(vec![
+                day.number.to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                "no input".to_string(),
+            ]);
+
+            continue;
+        };
+
+        let input_hash = ledger::hash_input(&input);
+        let report = (day.run)(&input, &[1, 2])?;
+
+        for part_report in report.parts {
+            let recorded = ledger.correct_answer(day.number, part_report.part, &input_hash);
+
+            let status = match recorded {
+                Some(recorded) if recorded == part_report.answer => "ok",
+                Some(_) => {
+                    drift_count += 1;
+                    "DRIFT"
+                }
+                None => "unrecorded",
+            };
+
+            table.push(vec![
+                day.number.to_string(),
+                part_report.part.to_string(),
+                part_report.answer,
+                recorded.unwrap_or_default().to_string(),
+                status.to_string(),
+            ]);
+        }
+    }
+
+    print!("{table}");
+
+    if drift_count > 0 {
+        return Err(format!("{drift_count} answers differ from the ledger").into());
+    }
+
+    Ok(())
+}
+
+fn submit(args: &Args) -> Result<()> {
+    args.expect_options(&["part", "input"])?;
+
+    let day_str = args.positional(1).ok_or("missing day to submit")?;
+
+    let day = match select_days(day_str)?[..] {
+        [day] => day,
+        _ => return Err("only one day can be submitted at a time".into()),
+    };
+
+    let config = Config::load()?;
+    let inputs = Inputs::new(inputs::DIR, &config);
+    let input = read_input(&inputs, day, args.option("input"))?;
+    let input_hash = ledger::hash_input(&input);
+    let mut ledger = Ledger::load(ledger::PATH)?;
+
+    let part = match args.option("part") {
+        Some(part) => args::parse_parts(Some(part))?[0],
+        None if ledger.correct_answer(day.number, 1, &input_hash).is_some() => 2,
+        None => 1,
+    };
+
+    let answer = (day.run)(&input, &[part])?.parts.remove(0).answer;
+    ledger.check_submission(day.number, part, &input_hash, &answer)?;
+    println!("Submitting {answer} for day {} part {part}", day.number);
+
+    match submit::submit(&config, inputs::DIR.as_ref(), day.number, part, &answer)? {
+        Outcome::Correct => {
+            ledger.record_correct(day.number, part, &input_hash, &answer)?;
+            println!("That's the right answer");
+        }
+        Outcome::Rejected(status) => {
+            ledger.record_rejected(day.number, part, &input_hash, status, &answer);
+            println!("That's not the right answer ({status})");
+        }
+        Outcome::Wait(wait) => {
+            let wait = table::format_duration(wait);
+            return Err(format!("answered too recently; try again in {wait}").into());
+        }
+        Outcome::AlreadySolved => {
+            return Err(format!("day {} part {part} is already solved", day.number).into());
+        }
+    }
+
+    ledger.save(ledger::PATH)?;
+    Ok(())
+}
+
+fn new(args: &Args) -> Result<()> {
+    args.expect_options(&[])?;
+
+    let day_str = args.positional(1).ok_or("missing day to create")?;
+    let day = day_str
+        .parse()
+        .map_err(|_| format!("`{day_str}` is not a valid day"))?;
+
+    for path in scaffold::new_day(".".as_ref(), day)? {
+        println!("Wrote {}", path.display());
+    }
+
+    println!("Paste the example into the sample and list its answers in examples/answers.txt");
+    Ok(())
+}
+
+/// What a day panicked with, as its error.
+fn panic_message(day: &Day, panic: &(dyn Any + Send)) -> String {
+    let message = panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message");
+
+    format!("day {} panicked: {message}", day.number)
+}
+
+/// Solves the day, returning its report along with the input's hash. The input is read a line at a
+/// time if the day supports it so that even huge inputs never have to fit in memory.
+fn run_day(
+    inputs: &Inputs,
+    day: &Day,
+    path: Option<&str>,
+    parts: &[u8],
+    (drawing, animation): (Option<&Drawing>, Option<&Playback>),
+) -> Result<(Report, String)> {
+    // Drawing and animating need the whole input, so it can't be streamed for them.
+    let stream = day
+        .stream
+        .filter(|_| drawing.is_none() && animation.is_none());
+
+    let Some(stream) = stream else {
+        let input = read_input(inputs, day, path)?;
+        let report = (day.run)(&input, parts)?;
+
+        if let Some(drawing) = drawing {
+            drawing.draw(day, &input)?;
+        }
+
+        if let Some(animation) = animation {
+            let animate = day.animate.ok_or("nothing to animate")?;
+            animation.play(day.number, animate(&input)?)?;
+        }
+
+        return Ok((report, ledger::hash_input(&input)));
+    };
+
+    let reader: Box<dyn BufRead> = match path {
+        Some("-") => Box::new(io::stdin().lock()),
+        Some(path) => Box::new(BufReader::new(open_input_file(day, path.as_ref())?)),
+        None => {
+            let path = inputs.download(day.number)?;
+            Box::new(BufReader::new(open_input_file(day, &path)?))
+        }
+    };
+
+    let mut reader = HashingReader::new(reader);
+    let report = stream(&mut reader, parts)?;
+    Ok((report, reader.hasher.finish()))
+}
+
+/// Where and how to draw a day, from `--render` and the options that go with it.
+struct Drawing {
+    path: PathBuf,
+    format: render::Format,
+    palette: render::Palette,
+    cell_size: usize,
+}
+
+impl Drawing {
+    fn new(args: &Args, path: &str) -> Result<Drawing> {
+        let path = PathBuf::from(path);
+        let format = render::Format::of(&path)?;
+        let palette = render::Palette::with_overrides(args.option("palette").unwrap_or(""))?;
+        let cell_size = args.parsed_option("cell-size", 8)?;
+
+        if cell_size == 0 {
+            return Err("--cell-size has to be at least 1".into());
+        }
+
+        Ok(Drawing {
+            path,
+            format,
+            palette,
+            cell_size,
+        })
+    }
+
+    fn draw(&self, day: &Day, input: &str) -> Result<()> {
+        let draw = day.render.ok_or("nothing to render")?;
+        let scene = draw(input)?;
+        let image = render::render(&scene, &self.palette, self.cell_size, self.format);
+
+        fs::write(&self.path, image).map_err(|error| {
+            let path = self.path.display();
+            format!("couldn't write the image to {path}: {error}").into()
+        })
+    }
+}
+
+/// Where to play a day's frames, from `--animate` and the options that go with it.
+fn playback(args: &Args, target: &str) -> Result<Playback> {
+    if target != "-" {
+        if args.option("fps").is_some() {
+            return Err(
+                "--fps only applies when animating in the terminal, with --animate -".into(),
+            );
+        }
+
+        return Ok(Playback::Directory(target.into()));
+    }
+
+    if !io::stdout().is_terminal() || !io::stdin().is_terminal() {
+        return Err(
+            "--animate - needs a terminal; give it a directory to write the frames to".into(),
+        );
+    }
+
+    let fps = args.parsed_option("fps", 10)?;
+    let palette = render::Palette::with_overrides(args.option("palette").unwrap_or(""))?;
+
+    if fps == 0 {
+        return Err("--fps has to be at least 1".into());
+    }
+
+    Ok(Playback::Terminal { fps, palette })
+}
+
+fn generate(args: &Args) -> Result<()> {
+    args.expect_options(&["seed", "size", "density"])?;
+
+    let day_str = args
+        .positional(1)
+        .ok_or("missing day to generate an input for")?;
+
+    let day = match select_days(day_str)?[..] {
+        [day] => day,
+        _ => return Err("only one day can be generated at a time".into()),
+    };
+
+    let options = generate_options(args)?;
+    let mut rng = Rng::new(args.parsed_option("seed", 0)?);
+    print!("{}", (day.generate)(&mut rng, &options));
+    Ok(())
+}
+
+fn cross_check(args: &Args) -> Result<()> {
+    args.expect_options(&["seeds", "size", "density"])?;
+
+    let days = select_days(args.positional(1).unwrap_or("all"))?;
+    let seeds = 0..args.parsed_option("seeds", 100)?;
+    let options = generate_options(args)?;
+
+    let checked: Vec<_> = days
+        .into_iter()
+        .filter_map(|day| Some((day.number, day.oracle?)))
+        .collect();
+
+    if checked.is_empty() {
+        return Err("none of those days have a reference implementation to check against".into());
+    }
+
+    let mut mismatch_count = 0;
+
+    for (number, oracle) in checked {
+        match oracle(seeds.clone(), &options) {
+            Ok(()) => println!("day {number}: ok, {} inputs agree", seeds.end),
+            Err(mismatch) => {
+                mismatch_count += 1;
+                println!("day {number}: {mismatch}");
+            }
+        }
+    }
+
+    if mismatch_count > 0 {
+        return Err(format!("{mismatch_count} days disagree with their reference").into());
+    }
+
+    Ok(())
+}
+
+fn repl(args: &Args) -> Result<()> {
+    args.expect_options(&["input"])?;
+
+    let day_str = args.positional(1).ok_or("missing day to explore")?;
+
+    let day = match select_days(day_str)?[..] {
+        [day] => day,
+        _ => return Err("only one day can be explored at a time".into()),
+    };
+
+    let explore = day
+        .explore
+        .ok_or_else(|| format!("day {} has nothing to explore", day.number))?;
+
+    let input_path = args.option("input");
+
+    if input_path == Some("-") {
+        return Err("the REPL reads queries from stdin, so the input can't come from it".into());
+    }
+
+    let config = Config::load()?;
+    let inputs = Inputs::new(inputs::DIR, &config);
+    let session = explore(&read_input(&inputs, day, input_path)?)?;
+
+    let mut words: Vec<_> = session.queries.iter().map(|query| query.name).collect();
+    words.extend(["help", "quit"]);
+    let mut editor = Editor::new(words.into_iter().map(String::from).collect());
+
+    if editor.is_interactive() {
+        println!(
+            "Day {} is loaded. Try `help` to see what it can be asked.",
+            day.number
+        );
+    }
+
+    let prompt = format!("day {}> ", day.number);
+
+    while let Some(line) = editor.read_line(&prompt)? {
+        let words: Vec<_> = line.split_whitespace().collect();
+
+        match words[..] {
+            [] => {}
+            ["quit" | "exit"] => break,
+            ["help"] => print!("{}", repl_help(session.queries)),
+            [query, ref args @ ..] => match session.ask(query, args) {
+                Ok(answer) => println!("{answer}"),
+                Err(error) => println!("error: {}", error.message()),
+            },
+        }
+    }
+
+    Ok(())
+}
+
+/// The queries a day answers, and the REPL's own commands, with what each does.
+fn repl_help(queries: &[Query]) -> String {
+    let commands: Vec<_> = queries
+        .iter()
+        .map(|query| {
+            let usage = iter::once(query.name).chain(query.args.iter().copied());
+            (usage.collect::<Vec<_>>().join(" "), query.about)
+        })
+        .chain([
+            ("help".to_string(), "list these queries"),
+            ("quit".to_string(), "leave"),
+        ])
+        .collect();
+
+    let width = commands.iter().map(|(usage, _)| usage.len()).max().unwrap();
+
+    commands
+        .iter()
+        .map(|(usage, about)| format!("  {usage:width$}  {about}\n"))
+        .collect()
+}
+
+/// Limits how many threads may be working at once, from `--jobs`.
+fn set_jobs(args: &Args) -> Result<()> {
+    let jobs = args.parsed_option("jobs", pool::jobs())?;
+
+    if jobs == 0 {
+        return Err("--jobs has to be at least 1".into());
+    }
+
+    pool::set_jobs(jobs);
+    Ok(())
+}
+
+fn generate_options(args: &Args) -> Result<generate::Options> {
+    Ok(generate::Options {
+        size: args.parsed_option("size", 100)?,
+        density: args
+            .option("density")
+            .map(|_| args.parsed_option("density", 0.0))
+            .transpose()?,
+    })
+}
+
+fn read_input(inputs: &Inputs, day: &Day, path: Option<&str>) -> Result<String> {
+    match path {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => read_input_file(day, path),
+        None => Ok(inputs.load(day.number)?),
+    }
+}
+
+fn read_input_file(day: &Day, path: &str) -> Result<String> {
+    fs::read_to_string(path).map_err(|error| input_file_error(day, path.as_ref(), error))
+}
+
+fn open_input_file(day: &Day, path: &Path) -> Result<File> {
+    File::open(path).map_err(|error| input_file_error(day, path, error))
+}
+
+fn input_file_error(day: &Day, path: &Path, error: io::Error) -> Box<dyn Error> {
+    let number = day.number;
+    let path = path.display();
+    format!("couldn't read day {number}'s input from {path}: {error}").into()
+}
+
+fn select_days(text: &str) -> Result<Vec<&'static Day>> {
+    if text == "all" {
+        return Ok(days::DAYS.iter().collect());
+    }
+
+    args::parse_selection(text)?
+        .into_iter()
+        .map(|number| {
+            days::find_day(number).ok_or_else(|| format!("day {number} isn't solved yet").into())
+        })
+        .collect()
+}
@@ -0,0 +1,147 @@
+//! Creates the files for a new day and registers it with the runner.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = r#"use aoc::generate::{Options, Rng};
+use aoc::solution::Print;
+use aoc::{parse, Result, Solution};
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u8 = N;
+
+    type Parsed = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(parse::lines(input).map(str::to_string).collect())
+    }
+
+    fn part_1(_lines: &Self::Parsed) -> Result<usize> {
+        // TODO
+        Ok(0)
+    }
+
+    fn part_2(_lines: &Self::Parsed) -> Result<usize> {
+        // TODO
+        Ok(0)
+    }
+}
+
+impl Print for DayNN {
+    fn print(lines: &Self::Parsed) -> String {
+        lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+}
+
+/// Random inputs for stress testing. The size is the number of lines.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    // TODO: lines shaped like the puzzle's. Every registered day's generator is run by the tests,
+    // so this has to give a valid input from the start.
+    (0..options.size)
+        .map(|_| format!("{}\n", rng.range(0..100)))
+        .collect()
+}
+
+#[test]
+fn sample() {
+    let input = include_str!("../../examples/dayNN/sample.txt");
+    let _lines = DayNN::parse(input).unwrap();
+}
+"#;
+
+/// Creates the module, an empty example and the registration for `day`, returning the paths of
+/// the files it touched. Nothing is written if any part of the day already exists.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there's no day {day}; days go from 1 to 25"));
+    }
+
+    let lib_path = root.join("days/src/lib.rs");
+    let module_path = root.join(format!("days/src/day{day:02}.rs"));
+    let example_dir = root.join(format!("examples/day{day:02}"));
+
+    let lib = fs::read_to_string(&lib_path)
+        .map_err(|_| "couldn't find days/src/lib.rs; run this from the workspace root")?;
+
+    for path in [&module_path, &example_dir] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+
+    let lib = register(&lib, day)?;
+    let example_path = example_dir.join("sample.txt");
+    let module = TEMPLATE
+        .replace("NN", &format!("{day:02}"))
+        .replace("= N;", &format!("= {day};"));
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|error| format!("couldn't write {path:?}: {error}"))
+    };
+
+    fs::create_dir_all(&example_dir)
+        .map_err(|error| format!("couldn't create {example_dir:?}: {error}"))?;
+
+    write(&module_path, &module)?;
+    write(&example_path, "")?;
+    write(&lib_path, &lib)?;
+    Ok(vec![module_path, example_path, lib_path])
+}
+
+/// Adds the day's module declaration and its entry in `DAYS`, keeping both in order.
+fn register(lib: &str, day: u8) -> Result<String, String> {
+    let module_line = format!("pub mod day{day:02};");
+    let entry_line = format!("    day!(day{day:02}::Day{day:02}),");
+
+    if lib.lines().any(|line| line == module_line) {
+        return Err(format!(
+            "day {day} is already registered in days/src/lib.rs"
+        ));
+    }
+
+    let mut lines: Vec<_> = lib.lines().collect();
+
+    for (line, prefix, suffix) in [
+        (&module_line, "pub mod day", ";"),
+        (&entry_line, "    day!(day", "),"),
+    ] {
+        // The existing lines for other days, as (index, day) pairs.
+        let days: Vec<(usize, u8)> = lines
+            .iter()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let number = line.strip_prefix(prefix)?.strip_suffix(suffix)?.get(..2)?;
+                Some((i, number.parse().ok()?))
+            })
+            .collect();
+
+        let index = match days.iter().find(|(_, other)| *other > day) {
+            Some(&(i, _)) => i,
+            None => match days.last() {
+                Some(&(i, _)) => i + 1,
+                None => return Err(format!("couldn't find where to add `{}`", line.trim())),
+            },
+        };
+
+        lines.insert(index, line);
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[test]
+fn registration() {
+    let lib = "pub mod day01;\npub mod day03;\n\npub const DAYS: &[Day] = &[\n    day!(day01::Day01),\n    day!(day03::Day03),\n];\n";
+
+    assert_eq!(
+        register(lib, 2).unwrap(),
+        "pub mod day01;\npub mod day02;\npub mod day03;\n\npub const DAYS: &[Day] = &[\n    day!(day01::Day01),\n    day!(day02::Day02),\n    day!(day03::Day03),\n];\n"
+    );
+
+    assert!(register(&register(lib, 4).unwrap(), 4).is_err());
+    assert!(register(lib, 3).is_err());
+    assert!(register("", 3).is_err());
+}
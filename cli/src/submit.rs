@@ -0,0 +1,158 @@
+//! Submitting answers to the puzzle site and making sense of its reply.
+
+use crate::config::Config;
+use crate::inputs;
+use crate::ledger::Status;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Rejected(Status), // wrong, too high or too low
+    Wait(Duration),   // an answer was given too recently
+    AlreadySolved,
+}
+
+/// The site makes you wait at least this long after a wrong answer before trying again.
+const WRONG_ANSWER_DELAY: Duration = Duration::from_secs(60);
+
+/// Posts the answer and returns what the site made of it. Submissions the site would refuse
+/// because the last one was too recent are caught here instead, without making a request.
+pub fn submit(
+    config: &Config,
+    dir: &Path,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, String> {
+    let wait_path = dir.join(".submit-after");
+
+    if let Some(wait) = read_time(&wait_path).and_then(|after| after.checked_sub(now())) {
+        return Ok(Outcome::Wait(wait));
+    }
+
+    let client = config.client()?;
+    let url = format!("{}/{}/day/{day}/answer", config.base_url, days::YEAR);
+    inputs::throttle(dir, config.rate_limit)?;
+
+    let response = client.post_form(&url, &[("level", &part.to_string()), ("answer", answer)])?;
+
+    if response.status != 200 {
+        let reason = response.body.lines().next().unwrap_or_default();
+        return Err(format!("couldn't submit ({}): {reason}", response.status));
+    }
+
+    let outcome = parse_response(&response.body)?;
+
+    let wait = match outcome {
+        Outcome::Rejected(_) => WRONG_ANSWER_DELAY,
+        Outcome::Wait(wait) => wait,
+        _ => Duration::ZERO,
+    };
+
+    fs::write(&wait_path, (now() + wait).as_millis().to_string())
+        .map_err(|error| format!("couldn't write {wait_path:?}: {error}"))?;
+
+    Ok(outcome)
+}
+
+pub fn parse_response(body: &str) -> Result<Outcome, String> {
+    if body.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if body.contains("That's not the right answer") {
+        let status = if body.contains("too high") {
+            Status::TooHigh
+        } else if body.contains("too low") {
+            Status::TooLow
+        } else {
+            Status::Wrong
+        };
+
+        Ok(Outcome::Rejected(status))
+    } else if body.contains("You gave an answer too recently") {
+        // "You have 1m 24s left to wait."
+        let wait = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait))
+            .unwrap_or(WRONG_ANSWER_DELAY);
+
+        Ok(Outcome::Wait(wait))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::AlreadySolved)
+    } else {
+        Err("couldn't make sense of the response to the submission".to_string())
+    }
+}
+
+/// Parses a wait like `1m 24s` or `38s`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    text.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let number: u64 = number.parse().ok()?;
+
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+fn now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap()
+}
+
+fn read_time(path: &Path) -> Option<Duration> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|text| text.trim().parse().ok())
+        .map(Duration::from_millis)
+}
+
+#[test]
+fn responses() {
+    let response = |message| format!("<main>\n<article><p>{message}</p></article>\n</main>");
+
+    let cases = [
+        (
+            "That's the right answer! You are one gold star closer to restoring snow operations.",
+            Outcome::Correct,
+        ),
+        (
+            "That's not the right answer; your answer is too high. Please wait one minute.",
+            Outcome::Rejected(Status::TooHigh),
+        ),
+        (
+            "That's not the right answer; your answer is too low.",
+            Outcome::Rejected(Status::TooLow),
+        ),
+        (
+            "That's not the right answer. If you're stuck, make sure you're using the full input.",
+            Outcome::Rejected(Status::Wrong),
+        ),
+        (
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have 1m 24s left to wait.",
+            Outcome::Wait(Duration::from_secs(84)),
+        ),
+        (
+            "You don't seem to be solving the right level.  Did you already complete it?",
+            Outcome::AlreadySolved,
+        ),
+    ];
+
+    for (message, outcome) in cases {
+        assert_eq!(parse_response(&response(message)), Ok(outcome));
+    }
+
+    assert!(parse_response("<html>Log in</html>").is_err());
+    assert_eq!(parse_wait("38s"), Some(Duration::from_secs(38)));
+    assert_eq!(parse_wait("soon"), None);
+}
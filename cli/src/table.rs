@@ -0,0 +1,103 @@
+//! Plain text tables with aligned columns.
+
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+
+#[derive(Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+pub struct Table {
+    columns: Vec<(&'static str, Align)>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(columns: &[(&'static str, Align)]) -> Table {
+        Table {
+            columns: columns.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let widths: Vec<_> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, (header, _))| {
+                self.rows
+                    .iter()
+                    .map(|row| row[i].chars().count())
+                    .chain([header.len()])
+                    .max()
+                    .unwrap()
+            })
+            .collect();
+
+        let write_row = |f: &mut Formatter, row: &[&str]| {
+            let cells: Vec<_> = row
+                .iter()
+                .zip(&self.columns)
+                .zip(&widths)
+                .map(|((cell, (_, align)), &width)| match align {
+                    Align::Left => format!("{cell:<width$}"),
+                    Align::Right => format!("{cell:>width$}"),
+                })
+                .collect();
+
+            writeln!(f, "{}", cells.join("  ").trim_end())
+        };
+
+        let header: Vec<_> = self.columns.iter().map(|(header, _)| *header).collect();
+        write_row(f, &header)?;
+
+        let separator: Vec<_> = widths.iter().map(|&width| "-".repeat(width)).collect();
+        write_row(f, &separator.iter().map(String::as_str).collect::<Vec<_>>())?;
+
+        for row in &self.rows {
+            write_row(f, &row.iter().map(String::as_str).collect::<Vec<_>>())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+
+    if micros < 1.0 {
+        format!("{}ns", duration.as_nanos())
+    } else if micros < 1_000.0 {
+        format!("{micros:.0}µs")
+    } else if micros < 1_000_000.0 {
+        format!("{:.1}ms", micros / 1_000.0)
+    } else {
+        format!("{:.2}s", micros / 1_000_000.0)
+    }
+}
+
+#[test]
+fn table() {
+    let mut table = Table::new(&[("Day", Align::Right), ("Answer", Align::Left)]);
+    table.push(vec!["1".to_string(), "142".to_string()]);
+    table.push(vec!["14".to_string(), "64".to_string()]);
+
+    assert_eq!(
+        table.to_string(),
+        "Day  Answer\n---  ------\n  1  142\n 14  64\n"
+    );
+
+    assert_eq!(format_duration(Duration::from_nanos(345)), "345ns");
+    assert_eq!(format_duration(Duration::from_micros(12)), "12µs");
+    assert_eq!(format_duration(Duration::from_micros(1_500)), "1.5ms");
+    assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+}
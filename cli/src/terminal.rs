@@ -0,0 +1,42 @@
+//! Just enough terminal handling to read keys as they're pressed, using `stty` rather than any
+//! terminal library.
+
+use std::process::{Command, Stdio};
+
+/// Keeps the terminal sending keys unbuffered and unechoed until it's dropped. Ctrl-C arrives as a
+/// key too, rather than killing the process with the terminal left this way.
+pub struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    pub fn enter() -> Result<RawMode, String> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+
+        Ok(RawMode {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .map_err(|error| format!("couldn't run stty: {error}"))?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("stty failed: {}", error.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
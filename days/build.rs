@@ -0,0 +1,49 @@
+//! Generates a test for every example listed in `examples/answers.txt` (see `src/examples.rs`).
+
+use std::path::Path;
+use std::{env, fs};
+
+fn main() {
+    let manifest_path =
+        Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("../examples/answers.txt");
+
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+    let mut tests = String::new();
+
+    for (i, line) in manifest.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<_> = line.split_whitespace().collect();
+
+        let (day, name) = match fields[..] {
+            [file, _, _] => file
+                .strip_prefix("day")
+                .and_then(|rest| rest.split_once('/'))
+                .and_then(|(day, name)| Some((day.parse::<u8>().ok()?, name))),
+            _ => None,
+        }
+        .unwrap_or_else(|| {
+            panic!(
+                "examples/answers.txt line {}: expected `dayNN/file.txt part answer`, found `{line}`",
+                i + 1
+            )
+        });
+
+        let (file, part, answer) = (fields[0], fields[1], fields[2]);
+
+        let stem = name
+            .trim_end_matches(".txt")
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+        tests += &format!(
+            "example_test!(day{day:02}_{stem}_part{part}, {day}, {part}, {file:?}, {answer:?});\n"
+        );
+    }
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out_path, tests).unwrap();
+}
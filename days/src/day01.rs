@@ -0,0 +1,244 @@
+use aoc::generate::{Options, Rng};
+use aoc::solution::{Print, Streaming};
+use aoc::{explain, parse, ParseError, Result, Solution};
+
+const NUMBERS: [(usize, &str); 9] = [
+    (1, "one"),
+    (2, "two"),
+    (3, "three"),
+    (4, "four"),
+    (5, "five"),
+    (6, "six"),
+    (7, "seven"),
+    (8, "eight"),
+    (9, "nine"),
+];
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse::lines(input)
+            .map(|line| parse_line(line).map(String::from))
+            .collect()
+    }
+
+    fn part_1(lines: &Self::Parsed) -> Result<usize> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| on_line(calibration_value(line), line, i + 1))
+            .sum()
+    }
+
+    fn part_2(lines: &Self::Parsed) -> Result<usize> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| on_line(spelled_calibration_value(line), line, i + 1))
+            .sum()
+    }
+}
+
+impl Print for Day01 {
+    fn print(lines: &Self::Parsed) -> String {
+        lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+}
+
+impl Streaming for Day01 {
+    type Record = String;
+    type Totals = usize;
+
+    fn parse_line(line: &str) -> Result<Self::Record> {
+        parse_line(line).map(String::from)
+    }
+
+    fn add_1(total: &mut usize, line: &String) -> Result<()> {
+        *total += calibration_value(line).map_err(|error| error.locate(line))?;
+
+        Ok(())
+    }
+
+    fn add_2(total: &mut usize, line: &String) -> Result<()> {
+        *total += spelled_calibration_value(line).map_err(|error| error.locate(line))?;
+
+        Ok(())
+    }
+
+    fn finish(total: usize) -> Result<usize> {
+        Ok(total)
+    }
+}
+
+/// Every line needs something to calibrate with, even if part 1 can't make use of spelled out
+/// numbers.
+fn parse_line(line: &str) -> Result<&str> {
+    if line.is_empty() {
+        let message = "expected a digit or a spelled out number, found a blank line";
+        return Err(ParseError::new(line, message));
+    }
+
+    if find_first_number(line).is_none() {
+        let message = format!("expected a digit or a spelled out number in `{line}`");
+        return Err(ParseError::new(line, message));
+    }
+
+    Ok(line)
+}
+
+/// Locates an error about one of the parsed lines, which are copies rather than slices of the
+/// input.
+fn on_line(value: Result<usize>, line: &str, line_number: usize) -> Result<usize> {
+    value.map_err(|error| error.locate(line).on_line(line_number))
+}
+
+fn calibration_value(line: &str) -> Result<usize> {
+    let no_digit = || ParseError::new(line, format!("expected a digit in `{line}`"));
+    let first_digit = find_digit(line.chars()).ok_or_else(no_digit)?;
+    let last_digit = find_digit(line.chars().rev()).ok_or_else(no_digit)?;
+
+    let value = format!("{first_digit}{last_digit}")
+        .parse::<usize>()
+        .unwrap();
+
+    explain!(
+        line = line,
+        first = first_digit,
+        last = last_digit,
+        value = value
+    );
+    Ok(value)
+}
+
+fn spelled_calibration_value(line: &str) -> Result<usize> {
+    let no_number = || {
+        let message = format!("expected a digit or a spelled out number in `{line}`");
+        ParseError::new(line, message)
+    };
+
+    let first_number = find_first_number(line).ok_or_else(no_number)?;
+    let last_number = find_last_number(line).ok_or_else(no_number)?;
+
+    let value = format!("{first_number}{last_number}")
+        .parse::<usize>()
+        .unwrap();
+
+    explain!(
+        line = line,
+        first = first_number,
+        last = last_number,
+        value = value
+    );
+    Ok(value)
+}
+
+fn find_digit(mut chars: impl Iterator<Item = char>) -> Option<usize> {
+    chars
+        .find_map(|c| c.to_digit(10))
+        .map(|digit| digit as usize)
+}
+
+fn find_first_number(line: &str) -> Option<usize> {
+    let mut result = None;
+    let mut first_index = None;
+
+    for (number, word) in NUMBERS.iter() {
+        if let Some(index) = line.find(word) {
+            if first_index.is_none() || index < first_index.unwrap() {
+                result = Some(*number);
+                first_index = Some(index);
+            }
+        }
+    }
+
+    if let Some(index) = line.find(|c: char| c.is_ascii_digit()) {
+        if first_index.is_none() || index < first_index.unwrap() {
+            result = Some(get_digit(line, index));
+        }
+    }
+
+    result
+}
+
+fn find_last_number(line: &str) -> Option<usize> {
+    let mut result = None;
+    let mut last_index = None;
+
+    for (number, word) in NUMBERS.iter() {
+        if let Some(index) = line.rfind(word) {
+            if last_index.is_none() || index > last_index.unwrap() {
+                result = Some(*number);
+                last_index = Some(index);
+            }
+        }
+    }
+
+    if let Some(index) = line.rfind(|c: char| c.is_ascii_digit()) {
+        if last_index.is_none() || index > last_index.unwrap() {
+            result = Some(get_digit(line, index));
+        }
+    }
+
+    result
+}
+
+/// The ASCII digit at the byte `index`.
+fn get_digit(line: &str, index: usize) -> usize {
+    usize::from(line.as_bytes()[index] - b'0')
+}
+
+/// Lines of letters with a digit somewhere in each and numbers spelled out here and there. The size
+/// is the number of lines.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let mut input = String::new();
+
+    for _ in 0..options.size {
+        let length = rng.range(1..10);
+        let digit_index = rng.range(0..length); // part 1 needs at least one digit per line
+
+        for i in 0..length {
+            if i == digit_index {
+                input.push(char::from(b'1' + rng.range(0..9) as u8));
+            } else if rng.chance(0.3) {
+                input += rng.choose(&NUMBERS).1;
+            } else {
+                input.push(char::from(b'a' + rng.range(0..26) as u8));
+            }
+        }
+
+        input.push('\n');
+    }
+
+    input
+}
+
+#[test]
+fn missing_digit() {
+    let lines = Day01::parse("1abc2\nabcone\n").unwrap();
+    let error = Day01::part_1(&lines).unwrap_err();
+    assert_eq!(error.message(), "expected a digit in `abcone`");
+    assert_eq!(error.location().map(|location| location.line), Some(2));
+    assert_eq!(Day01::part_2(&lines).unwrap(), 12 + 11);
+
+    let streamed = aoc::solution::run_stream::<Day01>(&mut "1abc2\nabcone\n".as_bytes(), &[1]);
+    let error = streamed.err().unwrap();
+    assert_eq!(error.location().map(|location| location.line), Some(2));
+
+    let input = "1abc2\nxyz\n";
+    let error = aoc::solution::parse::<Day01>(input).unwrap_err();
+    assert_eq!(
+        error.message(),
+        "expected a digit or a spelled out number in `xyz`"
+    );
+    assert_eq!(error.location().map(|location| location.line), Some(2));
+
+    let input = "1abc2\n\n";
+    let error = aoc::solution::parse::<Day01>(input).unwrap_err();
+    assert_eq!(error.location().map(|location| location.line), Some(2));
+}
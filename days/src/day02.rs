@@ -0,0 +1,182 @@
+use aoc::generate::{Options, Rng};
+use aoc::solution::{Print, Streaming};
+use aoc::{explain, parse, ParseError, Result, Solution};
+use std::cmp;
+
+pub type CubeValues = (usize, usize, usize); // red, green, blue
+pub type Game = (usize, Vec<CubeValues>); // ID, revealed cubes
+
+const CUBES_R: usize = 12;
+const CUBES_G: usize = 13;
+const CUBES_B: usize = 14;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Game>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse::lines(input).map(parse_game).collect()
+    }
+
+    fn part_1(games: &Self::Parsed) -> Result<usize> {
+        Ok(games
+            .iter()
+            .filter(|game| is_game_possible(game))
+            .map(|(id, _)| id)
+            .sum())
+    }
+
+    fn part_2(games: &Self::Parsed) -> Result<usize> {
+        Ok(games.iter().map(calculate_power).sum())
+    }
+}
+
+impl Print for Day02 {
+    fn print(games: &Self::Parsed) -> String {
+        games
+            .iter()
+            .map(|(id, values)| {
+                let values: Vec<_> = values
+                    .iter()
+                    .map(|(r, g, b)| format!("{r} red, {g} green, {b} blue"))
+                    .collect();
+
+                format!("Game {id}: {}\n", values.join("; "))
+            })
+            .collect()
+    }
+}
+
+impl Streaming for Day02 {
+    type Record = Game;
+    type Totals = usize;
+
+    fn parse_line(line: &str) -> Result<Self::Record> {
+        parse_game(line)
+    }
+
+    fn add_1(total: &mut usize, game: &Game) -> Result<()> {
+        if is_game_possible(game) {
+            *total += game.0;
+        }
+
+        Ok(())
+    }
+
+    fn add_2(total: &mut usize, game: &Game) -> Result<()> {
+        *total += calculate_power(game);
+
+        Ok(())
+    }
+
+    fn finish(total: usize) -> Result<usize> {
+        Ok(total)
+    }
+}
+
+fn calculate_power((id, values): &Game) -> usize {
+    let (r, g, b) = calculate_minimum_cubes_needed(values);
+    let power = r * g * b;
+
+    explain!(game = id, red = r, green = g, blue = b, power = power);
+    power
+}
+
+fn calculate_minimum_cubes_needed(values: &[CubeValues]) -> CubeValues {
+    let mut minimum_r = 0;
+    let mut minimum_g = 0;
+    let mut minimum_b = 0;
+
+    for &(r, g, b) in values {
+        minimum_r = cmp::max(minimum_r, r);
+        minimum_g = cmp::max(minimum_g, g);
+        minimum_b = cmp::max(minimum_b, b);
+    }
+
+    (minimum_r, minimum_g, minimum_b)
+}
+
+fn is_game_possible((id, values): &Game) -> bool {
+    let is_possible = values
+        .iter()
+        .all(|(r, g, b)| r <= &CUBES_R && g <= &CUBES_G && b <= &CUBES_B);
+
+    explain!(game = id, possible = is_possible);
+    is_possible
+}
+
+fn parse_game(line: &str) -> Result<Game> {
+    let (left, right) = parse::split_once(line, ": ")?;
+    let id = parse::number(parse::strip_prefix(left, "Game ")?)?;
+
+    let values = right
+        .split("; ")
+        .map(|values_str| {
+            let mut r = 0;
+            let mut g = 0;
+            let mut b = 0;
+
+            for cube_str in values_str.split(", ") {
+                let (number_str, color_str) = parse::split_once(cube_str, " ")?;
+                let number = parse::number(number_str)?;
+
+                match color_str {
+                    "red" => r = number,
+                    "green" => g = number,
+                    "blue" => b = number,
+                    _ => {
+                        let message = format!("expected red, green or blue, found `{color_str}`");
+                        return Err(ParseError::new(color_str, message));
+                    }
+                }
+            }
+
+            Ok((r, g, b))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((id, values))
+}
+
+/// Games of a few draws each, some of them possible with the bag's cubes and some not. The size is
+/// the number of games.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let mut input = String::new();
+
+    for id in 1..=options.size {
+        let mut draws = Vec::new();
+
+        for _ in 0..rng.range(1..7) {
+            let mut colors = ["red", "green", "blue"];
+            rng.shuffle(&mut colors);
+
+            let cubes: Vec<_> = colors[..rng.range(1..4)]
+                .iter()
+                .map(|color| format!("{} {color}", rng.range(1..17)))
+                .collect();
+
+            draws.push(cubes.join(", "));
+        }
+
+        input += &format!("Game {id}: {}\n", draws.join("; "));
+    }
+
+    input
+}
+
+#[test]
+fn malformed() {
+    let input = "Game 1: 3 blue, 4 red\nGame 2: 1 purple\n";
+    let error = aoc::solution::parse::<Day02>(input).unwrap_err();
+    let location = error.location().unwrap();
+
+    assert_eq!(
+        error.message(),
+        "expected red, green or blue, found `purple`"
+    );
+    assert_eq!((location.line, location.column), (2, 11));
+}
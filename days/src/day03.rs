@@ -0,0 +1,156 @@
+use aoc::generate::{Options, Rng};
+use aoc::grid::{self, Cell, Grid};
+use aoc::solution::Print;
+use aoc::{Result, Solution};
+use std::collections::{HashMap, HashSet};
+
+const GEAR_SYMBOL: char = '*';
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Grid;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        grid::parse_grid(input)
+    }
+
+    fn part_1(grid: &Self::Parsed) -> Result<usize> {
+        Ok(sum_part_numbers(grid))
+    }
+
+    fn part_2(grid: &Self::Parsed) -> Result<usize> {
+        Ok(sum_gear_ratios(grid))
+    }
+}
+
+impl Print for Day03 {
+    fn print(grid: &Self::Parsed) -> String {
+        grid.to_string()
+    }
+}
+
+fn sum_part_numbers(grid: &Grid) -> usize {
+    let mut result = 0;
+
+    for row in 0..grid.row_count() {
+        let mut part_number = 0;
+        let mut is_valid = false;
+
+        for col in 0..grid.col_count() {
+            if let Some(digit) = grid[(row, col)].to_digit(10) {
+                part_number = part_number * 10 + (digit as usize);
+
+                let is_adjacent_to_symbol = grid.neighbours_8((row, col)).any(|adjacent_cell| {
+                    let c = grid[adjacent_cell];
+                    !c.is_ascii_digit() && c != '.'
+                });
+
+                is_valid |= is_adjacent_to_symbol;
+            } else {
+                if is_valid {
+                    result += part_number;
+                }
+
+                part_number = 0;
+                is_valid = false;
+            }
+        }
+
+        if is_valid {
+            result += part_number;
+        }
+    }
+
+    result
+}
+
+fn sum_gear_ratios(grid: &Grid) -> usize {
+    let mut gear_part_numbers: HashMap<_, _> = get_gears(grid)
+        .into_iter()
+        .map(|cell| (cell, Vec::new()))
+        .collect();
+
+    let mut add_part_number_to_adjacent_gears = |part_number, part_adjacent_cells: &HashSet<_>| {
+        for cell in part_adjacent_cells {
+            if let Some(part_numbers) = gear_part_numbers.get_mut(cell) {
+                part_numbers.push(part_number);
+            }
+        }
+    };
+
+    for row in 0..grid.row_count() {
+        let mut part_number = 0;
+        let mut part_adjacent_cells = HashSet::new();
+
+        for col in 0..grid.col_count() {
+            if let Some(digit) = grid[(row, col)].to_digit(10) {
+                part_number = part_number * 10 + (digit as usize);
+
+                let adjacent_cells = grid
+                    .neighbours_8((row, col))
+                    .filter(|&adjacent_cell| !grid[adjacent_cell].is_ascii_digit());
+
+                part_adjacent_cells.extend(adjacent_cells);
+            } else {
+                add_part_number_to_adjacent_gears(part_number, &part_adjacent_cells);
+                part_number = 0;
+                part_adjacent_cells.clear();
+            }
+        }
+
+        add_part_number_to_adjacent_gears(part_number, &part_adjacent_cells);
+    }
+
+    gear_part_numbers
+        .iter()
+        .filter(|(_, part_numbers)| part_numbers.len() == 2)
+        .map(|(_, part_numbers)| part_numbers[0] * part_numbers[1])
+        .sum()
+}
+
+fn get_gears(grid: &Grid) -> Vec<Cell> {
+    grid.iter()
+        .filter(|(_, &c)| c == GEAR_SYMBOL)
+        .map(|(cell, _)| cell)
+        .collect()
+}
+
+/// A square schematic of numbers and symbols scattered among empty space. The size is the length of
+/// each side.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let side = options.size.max(1);
+    let mut input = String::new();
+
+    for _ in 0..side {
+        let mut row = String::new();
+
+        while row.len() < side {
+            if rng.chance(0.15) {
+                let length = rng.range(1..4).min(side - row.len());
+                row.push(char::from(b'1' + rng.range(0..9) as u8));
+
+                for _ in 1..length {
+                    row.push(char::from(b'0' + rng.range(0..10) as u8));
+                }
+
+                // Keep numbers apart so they aren't read as one.
+                if row.len() < side {
+                    row.push('.');
+                }
+            } else if rng.chance(0.05) {
+                row.push(*rng.choose(&['*', '#', '+', '$', '/', '@', '%', '=', '&', '-']));
+            } else {
+                row.push('.');
+            }
+        }
+
+        input += &row;
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,215 @@
+use aoc::generate::{Options, Rng};
+use aoc::solution::{Print, Streaming};
+use aoc::{parse, ParseError, Result, Solution};
+use std::collections::VecDeque;
+
+pub type Card = (Vec<usize>, Vec<usize>); // winning numbers, your numbers
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Card>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse::lines(input).map(parse_card).collect()
+    }
+
+    fn part_1(cards: &Self::Parsed) -> Result<usize> {
+        Ok(cards.iter().map(calculate_card_points).sum())
+    }
+
+    fn part_2(cards: &Self::Parsed) -> Result<usize> {
+        let mut card_counts = vec![1; cards.len()];
+
+        for (i, card) in cards.iter().enumerate() {
+            let number_of_matches = count_matches(card);
+
+            if i + number_of_matches >= cards.len() {
+                return Err(copies_past_last_card(cards.len()));
+            }
+
+            for offset in 1..=number_of_matches {
+                card_counts[i + offset] += card_counts[i];
+            }
+        }
+
+        Ok(card_counts.iter().sum())
+    }
+}
+
+impl Print for Day04 {
+    fn print(cards: &Self::Parsed) -> String {
+        cards
+            .iter()
+            .enumerate()
+            .map(|(i, (winning_numbers, your_numbers))| {
+                let join = |numbers: &[usize]| {
+                    let numbers: Vec<_> = numbers.iter().map(|n| format!("{n:2}")).collect();
+                    numbers.join(" ")
+                };
+
+                format!(
+                    "Card {}: {} | {}\n",
+                    i + 1,
+                    join(winning_numbers),
+                    join(your_numbers)
+                )
+            })
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct Totals {
+    total: usize,
+    card_count: usize,
+    upcoming_copies: VecDeque<usize>, // copies won of the next few cards, starting with the next
+}
+
+impl Streaming for Day04 {
+    type Record = Card;
+    type Totals = Totals;
+
+    fn parse_line(line: &str) -> Result<Self::Record> {
+        parse_card(line)
+    }
+
+    fn add_1(totals: &mut Totals, card: &Card) -> Result<()> {
+        totals.total += calculate_card_points(card);
+
+        Ok(())
+    }
+
+    fn add_2(totals: &mut Totals, card: &Card) -> Result<()> {
+        // Only the copies won of the next few cards need remembering, since a card can't win
+        // copies of cards further along than it has matches.
+        let card_count = 1 + totals.upcoming_copies.pop_front().unwrap_or(0);
+        let number_of_matches = count_matches(card);
+
+        if totals.upcoming_copies.len() < number_of_matches {
+            totals.upcoming_copies.resize(number_of_matches, 0);
+        }
+
+        for copies in totals.upcoming_copies.iter_mut().take(number_of_matches) {
+            *copies += card_count;
+        }
+
+        totals.total += card_count;
+        totals.card_count += 1;
+
+        Ok(())
+    }
+
+    fn finish(totals: Totals) -> Result<usize> {
+        // Copies still to come after the last card were won of cards that don't exist.
+        if !totals.upcoming_copies.is_empty() {
+            return Err(copies_past_last_card(totals.card_count));
+        }
+
+        Ok(totals.total)
+    }
+}
+
+fn copies_past_last_card(card_count: usize) -> ParseError {
+    let message = format!("a card wins copies of cards past the last, card {card_count}");
+    ParseError::new("", message)
+}
+
+fn count_matches((winning_numbers, your_numbers): &Card) -> usize {
+    your_numbers
+        .iter()
+        .filter(|number| winning_numbers.contains(number))
+        .count()
+}
+
+fn calculate_card_points(card: &Card) -> usize {
+    let mut result = 0;
+    let (winning_numbers, your_numbers) = card;
+
+    your_numbers
+        .iter()
+        .filter(|number| winning_numbers.contains(number))
+        .for_each(|_| {
+            result = if result == 0 { 1 } else { result * 2 };
+        });
+
+    result
+}
+
+fn parse_card(line: &str) -> Result<Card> {
+    let (_, numbers_str) = parse::split_once(line, ": ")?;
+    let (left, right) = parse::split_once(numbers_str, " | ")?;
+    let winning_numbers = parse::numbers(left)?;
+    let your_numbers = parse::numbers(right)?;
+    Ok((winning_numbers, your_numbers))
+}
+
+/// Scratchcards with ten winning numbers and twenty-five of your own. The size is the number of
+/// cards.
+///
+/// Most cards have no matches and the rest only a few, since the number of copies won in part 2
+/// grows exponentially if cards average more than one match.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let mut input = String::new();
+
+    for i in 0..options.size {
+        let mut numbers: Vec<usize> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+
+        // Cards can't win copies of cards past the end of the table.
+        let cards_after = options.size - i - 1;
+        let number_of_matches = if rng.chance(0.7) { 0 } else { rng.range(1..4) };
+        let number_of_matches = number_of_matches.min(cards_after);
+
+        let winning_numbers = &numbers[..10];
+        let mut your_numbers = numbers[..number_of_matches].to_vec();
+        your_numbers.extend(&numbers[10..35 - number_of_matches]);
+        rng.shuffle(&mut your_numbers);
+
+        let format_numbers = |numbers: &[usize]| {
+            let numbers: Vec<_> = numbers.iter().map(|number| format!("{number:2}")).collect();
+            numbers.join(" ")
+        };
+
+        input += &format!(
+            "Card {}: {} | {}\n",
+            i + 1,
+            format_numbers(winning_numbers),
+            format_numbers(&your_numbers)
+        );
+    }
+
+    input
+}
+
+#[test]
+fn streamed_and_whole_agree() {
+    use aoc::solution;
+
+    let answers = |input: &str, part| {
+        let answer = |report: solution::Report| report.parts[0].answer.clone();
+        let whole = solution::run::<Day04>(input, &[part]).map(answer);
+        let streamed = solution::run_stream::<Day04>(&mut input.as_bytes(), &[part]).map(answer);
+        let message = |error: aoc::ParseError| error.message().to_string();
+        (whole.map_err(message), streamed.map_err(message))
+    };
+
+    let (whole, streamed) = answers("Card 1: 1 2 | 1 3\nCard 2: 4 | 5\n", 2);
+    assert_eq!(whole, Ok("3".to_string()));
+    assert_eq!(streamed, whole);
+
+    // A card can't win copies of cards past the last, whichever way the cards are read.
+    let (whole, streamed) = answers("Card 1: 1 2 | 1 2\n", 2);
+    let message = "a card wins copies of cards past the last, card 1";
+    assert_eq!(whole, Err(message.to_string()));
+    assert_eq!(streamed, whole);
+
+    // Part 1 doesn't care where the copies would go.
+    assert_eq!(
+        answers("Card 1: 1 2 | 1 2\n", 1),
+        (Ok("2".into()), Ok("2".into()))
+    );
+}
@@ -0,0 +1,394 @@
+use aoc::generate::{Options, Rng};
+use aoc::solution::{Explore, Print, Query};
+use aoc::{oracle, pool};
+use aoc::{parse, ParseError, Result, Solution};
+use std::iter::Peekable;
+use std::ops::Range;
+
+pub type Map = Vec<(Range<usize>, usize)>; // source range, destination range start
+
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<Map>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = Almanac;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_almanac(input)
+    }
+
+    fn part_1(almanac: &Self::Parsed) -> Result<usize> {
+        Ok(almanac
+            .seeds
+            .iter()
+            .map(|&seed| {
+                almanac
+                    .maps
+                    .iter()
+                    .fold(seed, |source, map| get_destination(map, source))
+            })
+            .min()
+            .unwrap())
+    }
+
+    fn part_2(almanac: &Self::Parsed) -> Result<usize> {
+        // Rather than following every seed, follow whole ranges of them, splitting a range wherever
+        // it straddles the edge of a map's range.
+        let seed_ranges = seed_ranges(almanac).collect();
+
+        Ok(almanac
+            .maps
+            .iter()
+            .fold(seed_ranges, |ranges: Vec<_>, map| {
+                ranges
+                    .into_iter()
+                    .flat_map(|range| get_destinations(map, range))
+                    .collect()
+            })
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .unwrap())
+    }
+}
+
+impl Print for Day05 {
+    fn print(almanac: &Self::Parsed) -> String {
+        let seeds: Vec<_> = almanac.seeds.iter().map(|seed| seed.to_string()).collect();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+        // The categories aren't kept, so every map gets a made up header.
+        for (i, map) in almanac.maps.iter().enumerate() {
+            input += &format!("\nstage{i}-to-stage{} map:\n", i + 1);
+
+            for (source_range, destination_range_start) in map {
+                let (start, length) = (source_range.start, source_range.len());
+                input += &format!("{destination_range_start} {start} {length}\n");
+            }
+        }
+
+        input
+    }
+}
+
+impl Explore for Day05 {
+    const QUERIES: &'static [Query] = &[
+        Query {
+            name: "location",
+            args: &["<seed>"],
+            about: "the location the seed needs",
+        },
+        Query {
+            name: "path",
+            args: &["<seed>"],
+            about: "the number the seed maps to in each map, on the way to its location",
+        },
+    ];
+
+    fn query(almanac: &Self::Parsed, query: &str, args: &[&str]) -> Result<String> {
+        let seed = parse::number(args[0])?;
+        let path: Vec<_> = almanac
+            .maps
+            .iter()
+            .scan(seed, |source, map| {
+                *source = get_destination(map, *source);
+                Some(*source)
+            })
+            .collect();
+
+        match query {
+            "location" => Ok(path.last().unwrap_or(&seed).to_string()),
+            "path" => {
+                let numbers: Vec<_> = path.iter().map(|number| number.to_string()).collect();
+                Ok(format!("{seed} -> {}", numbers.join(" -> ")))
+            }
+            _ => unreachable!("unknown query `{query}`"),
+        }
+    }
+}
+
+fn seed_ranges(almanac: &Almanac) -> impl Iterator<Item = Range<usize>> + '_ {
+    almanac
+        .seeds
+        .chunks(2)
+        .map(|chunk| chunk[0]..(chunk[0] + chunk[1]))
+}
+
+/// Part 2 the slow but obvious way, following every seed through the maps. This is only kept as a
+/// reference to check the real one against.
+fn lowest_location_seed_by_seed(almanac: &Almanac) -> usize {
+    // Spreading the ranges over threads makes it faster, but even so it's slow.
+    let seed_ranges: Vec<_> = seed_ranges(almanac).collect();
+
+    pool::map(&seed_ranges, |range| {
+        range
+            .clone()
+            .map(|seed| {
+                almanac
+                    .maps
+                    .iter()
+                    .fold(seed, |source, map| get_destination(map, source))
+            })
+            .min()
+            .unwrap()
+    })
+    .into_iter()
+    .min()
+    .unwrap()
+}
+
+fn get_destination(map: &Map, source: usize) -> usize {
+    map.iter()
+        .find(|(source_range, _)| source_range.contains(&source))
+        .map(|(source_range, destination_range_start)| {
+            let range_index = source - source_range.start;
+            destination_range_start + range_index
+        })
+        .unwrap_or(source)
+}
+
+/// The ranges the sources map to. Like with single sources, the first of the map's ranges that
+/// contains a source wins.
+fn get_destinations(map: &Map, sources: Range<usize>) -> Vec<Range<usize>> {
+    let mut unmapped = vec![sources];
+    let mut destinations = Vec::new();
+
+    for (source_range, destination_range_start) in map {
+        let mut still_unmapped = Vec::new();
+
+        for sources in unmapped {
+            let overlap = sources.start.max(source_range.start)..sources.end.min(source_range.end);
+
+            if overlap.is_empty() {
+                still_unmapped.push(sources);
+                continue;
+            }
+
+            let offset = |source| destination_range_start + (source - source_range.start);
+            destinations.push(offset(overlap.start)..offset(overlap.end));
+
+            if sources.start < overlap.start {
+                still_unmapped.push(sources.start..overlap.start);
+            }
+
+            if overlap.end < sources.end {
+                still_unmapped.push(overlap.end..sources.end);
+            }
+        }
+
+        unmapped = still_unmapped;
+    }
+
+    destinations.extend(unmapped);
+    destinations
+}
+
+fn parse_almanac(input: &str) -> Result<Almanac> {
+    let mut lines = parse::lines(input).peekable();
+
+    let seeds_line = parse::next_line(&mut lines, input, "a list of seeds")?;
+    let seed_numbers: Vec<_> = parse::strip_prefix(seeds_line, "seeds:")?
+        .split_whitespace()
+        .collect();
+    let seeds = seed_numbers
+        .iter()
+        .map(|number| parse::number(number))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Part 2 reads the seeds as pairs of a start and a length.
+    if seeds.is_empty() || seeds.len() % 2 != 0 {
+        let message = format!("expected an even number of seeds, found {}", seeds.len());
+        return Err(ParseError::new(seeds_line, message));
+    }
+
+    for (i, pair) in seeds.chunks(2).enumerate() {
+        range_end(pair[0], pair[1], seed_numbers[2 * i + 1])?;
+    }
+
+    lines.next();
+
+    let mut maps = Vec::new();
+
+    while lines.peek().is_some() {
+        let map = parse_map(&mut lines)?;
+        maps.push(map);
+    }
+
+    Ok(Almanac { seeds, maps })
+}
+
+fn parse_map<'a>(lines: &mut Peekable<impl Iterator<Item = &'a str>>) -> Result<Map> {
+    // Ignore header, beyond checking that it is one.
+    if let Some(header) = lines.next() {
+        parse::strip_suffix(header, " map:")?;
+    }
+
+    lines
+        .take_while(|line| !line.is_empty())
+        .map(|line| {
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let numbers = fields
+                .iter()
+                .map(|field| parse::number(field))
+                .collect::<Result<Vec<usize>>>()?;
+
+            if numbers.len() != 3 {
+                let message = format!("expected 3 numbers, found {}", numbers.len());
+                return Err(ParseError::new(line, message));
+            }
+
+            let destination_range_start = numbers[0];
+            let source_range_start = numbers[1];
+            let range_length = numbers[2];
+
+            // Mapped numbers go up to the end of the destination range, so it has to fit too.
+            range_end(destination_range_start, range_length, fields[2])?;
+            let source_range_end = range_end(source_range_start, range_length, fields[2])?;
+            let source_range = source_range_start..source_range_end;
+
+            Ok((source_range, destination_range_start))
+        })
+        .collect()
+}
+
+/// Where a range starting at `start` and `length` long ends, as long as it fits in a `usize`,
+/// pointing any error at the length.
+fn range_end(start: usize, length: usize, length_text: &str) -> Result<usize> {
+    start.checked_add(length).ok_or_else(|| {
+        let message = format!("a range of {length} from {start} goes past {}", usize::MAX);
+        ParseError::new(length_text, message)
+    })
+}
+
+/// An almanac with ten seeds and a chain of maps, each shuffling part of the numbers below 2³² like
+/// the real ones do. The size is the number of maps.
+///
+/// The seed ranges are kept short, since the reference for part 2 checks every seed in them.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    const UNIVERSE: usize = 1 << 32;
+
+    let map_count = options.size.max(1);
+    let seeds: Vec<_> = (0..5)
+        .flat_map(|_| [rng.range(0..UNIVERSE - 10_000), rng.range(1..10_000)])
+        .collect();
+
+    let numbers: Vec<_> = seeds.iter().map(|number| number.to_string()).collect();
+    let mut input = format!("seeds: {}\n", numbers.join(" "));
+
+    // Where the start of each seed range has got to, so maps can split the ranges like real ones.
+    let mut followed: Vec<_> = seeds.iter().step_by(2).copied().collect();
+
+    let category = |i| match (i, map_count) {
+        (0, _) => "seed".to_string(),
+        (i, n) if i == n => "location".to_string(),
+        (i, 7) => [
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+        ][i - 1]
+            .into(),
+        (i, _) => format!("stage{i}"),
+    };
+
+    for i in 0..map_count {
+        input += &format!("\n{}-to-{} map:\n", category(i), category(i + 1));
+
+        // Split a stretch of numbers into ranges, then move the ranges around within it.
+        let mut bounds: Vec<_> = (0..rng.range(2..9))
+            .map(|_| rng.range(0..UNIVERSE))
+            .collect();
+
+        for &number in &followed {
+            if rng.chance(0.5) {
+                bounds.push((number + rng.range(1..10_000)).min(UNIVERSE - 1));
+            }
+        }
+
+        bounds.sort();
+        bounds.dedup();
+
+        let mut ranges: Vec<_> = bounds
+            .windows(2)
+            .map(|pair| (pair[0], pair[1] - pair[0]))
+            .collect();
+        let mut destination = bounds[0];
+        rng.shuffle(&mut ranges);
+
+        let mut map = Map::new();
+
+        for (source, length) in ranges {
+            input += &format!("{destination} {source} {length}\n");
+            map.push((source..source + length, destination));
+            destination += length;
+        }
+
+        for number in &mut followed {
+            *number = get_destination(&map, *number);
+        }
+    }
+
+    input
+}
+
+/// Checks part 2 against following every seed, on generated almanacs.
+pub fn cross_check(seeds: Range<u64>, options: &Options) -> std::result::Result<(), String> {
+    // Minimising can make seed ranges empty, which doesn't happen in real almanacs. It can also turn
+    // a seed into a length by taking out the one before it, making a range far too long to check
+    // seed by seed.
+    let parse = |input: &str| {
+        parse_almanac(input).ok().filter(|almanac| {
+            seed_ranges(almanac).all(|range| (1..=100_000).contains(&range.len()))
+        })
+    };
+
+    oracle::cross_check(
+        seeds,
+        |rng| generate(rng, options),
+        |input| parse(input).map(|almanac| lowest_location_seed_by_seed(&almanac)),
+        |input| parse(input).and_then(|almanac| Day05::part_2(&almanac).ok()),
+    )
+    .map_err(|mismatch| mismatch.to_string())
+}
+
+#[test]
+fn reference() {
+    assert_eq!(cross_check(0..20, &Options::new(7)), Ok(()));
+}
+
+#[test]
+fn malformed_seeds() {
+    for (seeds, found) in [("seeds: 79 14 55", 3), ("seeds: ", 0)] {
+        let input = format!("{seeds}\n\nseed-to-soil map:\n50 98 2\n");
+        let error = aoc::solution::parse::<Day05>(&input).unwrap_err();
+        let location = error.location().unwrap();
+
+        let message = format!("expected an even number of seeds, found {found}");
+        assert_eq!(error.message(), message);
+        assert_eq!((location.line, location.width), (1, seeds.trim_end().len()));
+    }
+
+    // Ranges that run past the largest number point at their length.
+    let inputs = [
+        "seeds: 18446744073709551615 5\n",
+        "seeds: 1 2\n\nseed-to-soil map:\n5 18446744073709551615 5\n",
+        "seeds: 1 2\n\nseed-to-soil map:\n18446744073709551615 5 5\n",
+    ];
+
+    for input in inputs {
+        let error = aoc::solution::parse::<Day05>(input).unwrap_err();
+        let location = error.location().unwrap();
+        let line = input.lines().last().unwrap();
+        assert_eq!(location.column, line.rfind('5').unwrap() + 1, "{input}");
+    }
+}
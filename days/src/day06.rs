@@ -0,0 +1,197 @@
+use aoc::generate::{Options, Rng};
+use aoc::oracle;
+use aoc::solution::Print;
+use aoc::{parse, ParseError, Result, Solution};
+use std::iter;
+use std::ops::Range;
+
+pub type Race = (usize, usize); // duration, record distance
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = Vec<Race>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let mut lines = parse::lines(input);
+        let race_durations_line = parse::next_line(&mut lines, input, "race durations")?;
+        let records_line = parse::next_line(&mut lines, input, "record distances")?;
+        let race_durations = parse::numbers(parse::strip_prefix(race_durations_line, "Time:")?)?;
+        let records: Vec<_> = parse::numbers(parse::strip_prefix(records_line, "Distance:")?)?;
+
+        if records.len() != race_durations.len() {
+            let message = format!(
+                "expected {} distances to match the race durations, found {}",
+                race_durations.len(),
+                records.len()
+            );
+
+            return Err(ParseError::new(records_line, message));
+        }
+
+        if race_durations.is_empty() {
+            return Err(ParseError::new(
+                race_durations_line,
+                "expected at least one race",
+            ));
+        }
+
+        Ok(race_durations.into_iter().zip(records).collect())
+    }
+
+    fn part_1(races: &Self::Parsed) -> Result<usize> {
+        races
+            .iter()
+            .map(|&(race_duration, record)| count_ways_to_beat_record(race_duration, record))
+            .try_fold(1, usize::checked_mul)
+            .ok_or_else(|| ParseError::new("", "too many ways to win to count"))
+    }
+
+    fn part_2(races: &Self::Parsed) -> Result<usize> {
+        let (race_duration, record) = as_one_race(races)?;
+        Ok(count_ways_to_beat_record(race_duration, record))
+    }
+}
+
+impl Print for Day06 {
+    fn print(races: &Self::Parsed) -> String {
+        let durations: Vec<_> = races
+            .iter()
+            .map(|(duration, _)| duration.to_string())
+            .collect();
+        let records: Vec<_> = races.iter().map(|(_, record)| record.to_string()).collect();
+        format!(
+            "Time: {}\nDistance: {}\n",
+            durations.join(" "),
+            records.join(" ")
+        )
+    }
+}
+
+/// There's actually only one race; the spaces between its digits were bad kerning.
+fn as_one_race(races: &[Race]) -> Result<Race> {
+    let race_duration = join_digits(races.iter().map(|(race_duration, _)| race_duration))?;
+    let record = join_digits(races.iter().map(|(_, record)| record))?;
+    Ok((race_duration, record))
+}
+
+fn count_ways_to_beat_record(race_duration: usize, record: usize) -> usize {
+    // Holding the button for b ms goes b * (race_duration - b), which beats the record for every b
+    // between the roots of b² - race_duration * b + record = 0. Floats could put the first root out
+    // by one, so it's nudged onto the first winning b after. The distances are worked out in a
+    // wider type, which two durations multiplied can't overflow.
+    let beats_record = |button_duration| distance(race_duration, button_duration) > record as u128;
+    let best = race_duration / 2;
+
+    if !beats_record(best) {
+        return 0;
+    }
+
+    let discriminant = (race_duration as f64).powi(2) - 4.0 * record as f64;
+    let first_root = (race_duration as f64 - discriminant.max(0.0).sqrt()) / 2.0;
+    let mut first = (first_root.max(0.0) as usize).min(best);
+
+    while first > 0 && beats_record(first - 1) {
+        first -= 1;
+    }
+
+    while !beats_record(first) {
+        first += 1;
+    }
+
+    // The winning times are symmetric around the best one.
+    race_duration - 2 * first + 1
+}
+
+/// The slow but obvious way, trying every time. This is only kept as a reference to check the real
+/// one against.
+fn count_ways_by_trying_each(race_duration: usize, record: usize) -> usize {
+    (1..race_duration)
+        .map(|button_duration| distance(race_duration, button_duration))
+        .filter(|&distance| distance > record as u128)
+        .count()
+}
+
+/// How far the boat goes holding the button for `button_duration` ms of the race.
+fn distance(race_duration: usize, button_duration: usize) -> u128 {
+    (race_duration - button_duration) as u128 * button_duration as u128
+}
+
+fn join_digits<'a>(numbers: impl Iterator<Item = &'a usize>) -> Result<usize> {
+    let digits: String = numbers.map(|number| number.to_string()).collect();
+
+    digits.parse().map_err(|_| {
+        let message = format!("the races joined up, {digits}, are too long to count");
+        ParseError::new("", message)
+    })
+}
+
+/// Races that can each be won. The size is the number of races, up to four, as more would make the
+/// single race in part 2 too long for the reference to count.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let races: Vec<_> = (0..options.size.clamp(1, 4))
+        .map(|_| {
+            let race_duration = rng.range(7..100);
+            let best_distance = (race_duration / 2) * (race_duration - race_duration / 2);
+            (race_duration, rng.range(1..best_distance))
+        })
+        .collect();
+
+    let durations: Vec<_> = races
+        .iter()
+        .map(|(duration, _)| format!("{duration:4}"))
+        .collect();
+    let records: Vec<_> = races
+        .iter()
+        .map(|(_, record)| format!("{record:4}"))
+        .collect();
+    format!(
+        "Time:    {}\nDistance:{}\n",
+        durations.join(" "),
+        records.join(" ")
+    )
+}
+
+/// Checks the count of ways to win each race, and the one long race, against trying every time.
+pub fn cross_check(seeds: Range<u64>, options: &Options) -> std::result::Result<(), String> {
+    let counts = |input: &str, count: fn(usize, usize) -> usize| {
+        let races = Day06::parse(input).ok()?;
+        let counts: Vec<_> = races
+            .iter()
+            .chain(iter::once(&as_one_race(&races).ok()?))
+            .map(|&(race_duration, record)| count(race_duration, record))
+            .collect();
+
+        Some(format!("{counts:?}"))
+    };
+
+    oracle::cross_check(
+        seeds,
+        |rng| generate(rng, options),
+        |input| counts(input, count_ways_by_trying_each),
+        |input| counts(input, count_ways_to_beat_record),
+    )
+    .map_err(|mismatch| mismatch.to_string())
+}
+
+#[test]
+fn reference() {
+    assert_eq!(cross_check(0..50, &Options::new(2)), Ok(()));
+}
+
+#[test]
+fn large_races() {
+    let races = Day06::parse("Time: 99999999999\nDistance: 99999999999\n").unwrap();
+    assert_eq!(Day06::part_1(&races).unwrap(), 99999999996);
+    assert_eq!(Day06::part_2(&races).unwrap(), 99999999996);
+
+    let races = Day06::parse("Time: 99999999999 99999999999\nDistance: 1 1\n").unwrap();
+    assert!(Day06::part_1(&races).is_err());
+    assert!(Day06::part_2(&races).is_err());
+
+    let error = aoc::solution::parse::<Day06>("Time:\nDistance:\n").unwrap_err();
+    assert_eq!(error.message(), "expected at least one race");
+}
@@ -0,0 +1,277 @@
+use aoc::generate::{Options, Rng};
+use aoc::solution::{Print, Streaming};
+use aoc::{explain, parse, ParseError, Result, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    fn from(c: char) -> Option<Card> {
+        let card = match c {
+            '2' => Card::Two,
+            '3' => Card::Three,
+            '4' => Card::Four,
+            '5' => Card::Five,
+            '6' => Card::Six,
+            '7' => Card::Seven,
+            '8' => Card::Eight,
+            '9' => Card::Nine,
+            'T' => Card::Ten,
+            'J' => Card::Jack,
+            'Q' => Card::Queen,
+            'K' => Card::King,
+            'A' => Card::Ace,
+            _ => return None,
+        };
+
+        Some(card)
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::Jack | Card::Joker => 'J',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+            Card::Ace => 'A',
+        }
+    }
+
+    // In part 2, J cards are jokers rather than jacks.
+    fn with_jokers(self) -> Card {
+        match self {
+            Card::Jack => Card::Joker,
+            card => card,
+        }
+    }
+}
+
+pub type Hand = [Card; 5];
+
+const CARD_LABELS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<(Hand, usize)>; // hand, bid
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse::lines(input).map(parse_hand).collect()
+    }
+
+    fn part_1(hands: &Self::Parsed) -> Result<usize> {
+        let mut hands = hands.clone();
+        hands.sort_by_key(|(hand, _)| (get_hand_type_ignoring_jokers(hand), hand.clone()));
+        Ok(calculate_total_winnings(hands))
+    }
+
+    fn part_2(hands: &Self::Parsed) -> Result<usize> {
+        let mut hands: Vec<_> = hands
+            .iter()
+            .map(|(hand, bid)| (hand.clone().map(Card::with_jokers), *bid))
+            .collect();
+
+        hands.sort_by_key(|(hand, _)| (get_hand_type(hand), hand.clone()));
+        Ok(calculate_total_winnings(hands))
+    }
+}
+
+impl Print for Day07 {
+    fn print(hands: &Self::Parsed) -> String {
+        hands
+            .iter()
+            .map(|(hand, bid)| {
+                let hand: String = hand.iter().map(Card::to_char).collect();
+                format!("{hand} {bid}\n")
+            })
+            .collect()
+    }
+}
+
+impl Streaming for Day07 {
+    type Record = (Hand, usize);
+
+    // A hand's rank depends on every other hand, so unlike other days every hand has to be kept
+    // until the end, and the memory used grows with the input like reading it whole would.
+    type Totals = Vec<(Hand, usize)>;
+
+    fn parse_line(line: &str) -> Result<Self::Record> {
+        parse_hand(line)
+    }
+
+    fn add_1(hands: &mut Self::Totals, hand: &Self::Record) -> Result<()> {
+        hands.push(hand.clone());
+
+        Ok(())
+    }
+
+    fn add_2(hands: &mut Self::Totals, (hand, bid): &Self::Record) -> Result<()> {
+        hands.push((hand.clone().map(Card::with_jokers), *bid));
+
+        Ok(())
+    }
+
+    fn finish(mut hands: Self::Totals) -> Result<usize> {
+        // Without any jokers in the hands this ranks them just as part 1 does.
+        hands.sort_by_key(|(hand, _)| (get_hand_type(hand), hand.clone()));
+        Ok(calculate_total_winnings(hands))
+    }
+}
+
+fn calculate_total_winnings(hands: Vec<(Hand, usize)>) -> usize {
+    hands
+        .into_iter()
+        .enumerate()
+        .map(|(i, (hand, bid))| {
+            let rank = i + 1;
+
+            // Without jokers this is the same type part 1 ranks the hand by.
+            explain!(
+                hand = hand.iter().map(Card::to_char).collect::<String>(),
+                kind = format!("{:?}", get_hand_type(&hand)),
+                rank = rank,
+                bid = bid,
+                winnings = bid * rank,
+            );
+
+            bid * rank
+        })
+        .sum()
+}
+
+fn get_hand_type(hand: &Hand) -> HandType {
+    let hand_type = get_hand_type_ignoring_jokers(hand);
+    let joker_count = hand.iter().filter(|&card| card == &Card::Joker).count();
+
+    match (hand_type, joker_count) {
+        (HandType::HighCard, 1) => HandType::OnePair,
+        (HandType::HighCard, 2) => HandType::ThreeOfAKind,
+        (HandType::HighCard, 3) => HandType::FourOfAKind,
+        (HandType::HighCard, 4) => HandType::FiveOfAKind,
+        (HandType::HighCard, 5) => HandType::FiveOfAKind,
+
+        (HandType::OnePair, 1) => HandType::ThreeOfAKind,
+        (HandType::OnePair, 2) => HandType::FourOfAKind,
+        (HandType::OnePair, 3) => HandType::FiveOfAKind,
+
+        (HandType::TwoPair, 1) => HandType::FullHouse,
+
+        (HandType::ThreeOfAKind, 1) => HandType::FourOfAKind,
+        (HandType::ThreeOfAKind, 2) => HandType::FiveOfAKind,
+
+        (HandType::FourOfAKind, 1) => HandType::FiveOfAKind,
+
+        (hand_type, _) => hand_type,
+    }
+}
+
+fn get_hand_type_ignoring_jokers(hand: &Hand) -> HandType {
+    let card_counts: Vec<_> = hand
+        .iter()
+        .filter(|&card| card != &Card::Joker)
+        .fold(HashMap::new(), |mut map, card| {
+            map.entry(card).and_modify(|count| *count += 1).or_insert(1);
+            map
+        })
+        .into_values()
+        .collect();
+
+    if card_counts.contains(&5) {
+        return HandType::FiveOfAKind;
+    }
+
+    if card_counts.contains(&4) {
+        return HandType::FourOfAKind;
+    }
+
+    if card_counts.contains(&3) && card_counts.contains(&2) {
+        return HandType::FullHouse;
+    }
+
+    if card_counts.contains(&3) {
+        return HandType::ThreeOfAKind;
+    }
+
+    if card_counts.iter().filter(|&count| count == &2).count() == 2 {
+        return HandType::TwoPair;
+    }
+
+    if card_counts.contains(&2) {
+        return HandType::OnePair;
+    }
+
+    HandType::HighCard
+}
+
+fn parse_hand(line: &str) -> Result<(Hand, usize)> {
+    let (hand_str, bid_str) = parse::split_once(line, " ")?;
+
+    let cards = hand_str
+        .char_indices()
+        .map(|(i, c)| {
+            Card::from(c).ok_or_else(|| {
+                ParseError::new(parse::char_span(hand_str, i), format!("unknown card `{c}`"))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let hand = cards.try_into().map_err(|cards: Vec<_>| {
+        let message = format!("expected a hand of 5 cards, found {}", cards.len());
+        ParseError::new(hand_str, message)
+    })?;
+
+    let bid = parse::number(bid_str)?;
+    Ok((hand, bid))
+}
+
+/// Random hands with bids. The size is the number of hands.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let mut input = String::new();
+
+    for _ in 0..options.size {
+        let hand: String = (0..5).map(|_| *rng.choose(&CARD_LABELS)).collect();
+        input += &format!("{hand} {}\n", rng.range(1..1001));
+    }
+
+    input
+}
@@ -0,0 +1,318 @@
+use aoc::generate::{Options, Rng};
+use aoc::solution::{Explore, Print, Query};
+use aoc::{cycle, math};
+use aoc::{parse, ParseError, Result, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub type Network = HashMap<String, (String, String)>; // node, (left node, right node)
+
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    pub instructions: String,
+    pub network: Network,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = Map;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_map(input)
+    }
+
+    fn part_1(map: &Self::Parsed) -> Result<usize> {
+        // Part 2's networks needn't have an `AAA`, so this can't be checked while parsing.
+        if !map.network.contains_key("AAA") {
+            return Err(ParseError::new("", "expected a node `AAA` to start from"));
+        }
+
+        Ok(count_steps_for_node(
+            &map.instructions,
+            &map.network,
+            "AAA",
+            "ZZZ",
+        ))
+    }
+
+    fn part_2(map: &Self::Parsed) -> Result<usize> {
+        count_ghost_steps(map)
+    }
+}
+
+impl Print for Day08 {
+    fn print(map: &Self::Parsed) -> String {
+        // Nodes are sorted so the same network always prints the same.
+        let mut nodes: Vec<_> = map.network.iter().collect();
+        nodes.sort();
+
+        let mut input = format!("{}\n\n", map.instructions);
+
+        for (node, (left, right)) in nodes {
+            input += &format!("{node} = ({left}, {right})\n");
+        }
+
+        input
+    }
+}
+
+impl Explore for Day08 {
+    const QUERIES: &'static [Query] = &[
+        Query {
+            name: "next",
+            args: &["<node>"],
+            about: "the nodes left and right of the node",
+        },
+        Query {
+            name: "walk",
+            args: &["<node>", "<steps>"],
+            about: "where following the instructions from the node leads after that many steps",
+        },
+    ];
+
+    fn query(map: &Self::Parsed, query: &str, args: &[&str]) -> Result<String> {
+        let Some((node, (left, right))) = map.network.get_key_value(args[0]) else {
+            let message = format!("node `{}` isn't in the network", args[0]);
+            return Err(ParseError::new(args[0], message));
+        };
+
+        match query {
+            "next" => Ok(format!("left {left}, right {right}")),
+            "walk" => {
+                let steps = parse::number(args[1])?;
+                let start = (node.as_str(), 0);
+                let (node, _) = cycle::nth(start, |&position| ghost_step(map, position), steps);
+                Ok(node.to_string())
+            }
+            _ => unreachable!("unknown query `{query}`"),
+        }
+    }
+}
+
+fn count_ghost_steps(map: &Map) -> Result<usize> {
+    // Each ghost's position is its node plus how far through the instructions it is. There are
+    // only so many of those, so every ghost ends up going round a loop, and from then on it's at
+    // an end node at steps that repeat with the length of the loop. The ghosts are all at end
+    // nodes together at a step that matches one of those repeats for every ghost.
+    let ghosts: Vec<_> = map
+        .network
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .map(|node| cycle::detect((node.as_str(), 0), |&position| ghost_step(map, position)))
+        .collect();
+
+    let is_finished = |step| ghosts.iter().all(|ghost| ghost.nth(step).0.ends_with('Z'));
+    let looping_from = ghosts.iter().map(|ghost| ghost.cycle.start).max().unwrap();
+
+    // Before every ghost is in its loop the ghosts have to be checked one step at a time.
+    if let Some(step) = (0..looping_from).find(|&step| is_finished(step)) {
+        return Ok(step);
+    }
+
+    // After that, a ghost is at an end node at any step that's the same as one of its end steps
+    // within the loop, modulo the loop's length. Solving those congruences for every ghost gives
+    // the steps they line up on.
+    let mut candidates = vec![(0, 1)]; // step, modulo the length of the ghosts' combined loops
+
+    for ghost in &ghosts {
+        let period = ghost.cycle.period;
+        let end_steps: Vec<_> = (ghost.cycle.start..ghost.states.len())
+            .filter(|&step| ghost.states[step].0.ends_with('Z'))
+            .map(|step| (step as i128, period as i128))
+            .collect();
+
+        candidates = candidates
+            .into_iter()
+            .flat_map(|candidate| {
+                end_steps
+                    .iter()
+                    .filter_map(move |&end| math::crt([candidate, end]))
+            })
+            .collect();
+    }
+
+    candidates
+        .into_iter()
+        .map(|(step, period)| {
+            looping_from as i128 + (step - looping_from as i128).rem_euclid(period)
+        })
+        .min()
+        .ok_or_else(|| ParseError::new("", "the ghosts are never all at end nodes at once"))
+        .and_then(|step| {
+            usize::try_from(step).map_err(|_| ParseError::new("", "too many steps to count"))
+        })
+}
+
+/// Moves a ghost along one step, where its position is a node and an index into the instructions.
+fn ghost_step<'a>(map: &'a Map, (node, i): (&str, usize)) -> (&'a str, usize) {
+    let (left, right) = &map.network[node];
+    let next_node = match map.instructions.as_bytes()[i] {
+        b'L' => left,
+        _ => right,
+    };
+
+    (next_node, (i + 1) % map.instructions.len())
+}
+
+fn count_steps_for_node(
+    instructions: &str,
+    network: &Network,
+    start_node: &str,
+    end_node_suffix: &str,
+) -> usize {
+    instructions
+        .chars()
+        .cycle()
+        .scan(start_node, |node, direction| {
+            if node.ends_with(end_node_suffix) {
+                return None;
+            }
+
+            let (left, right) = &network[*node];
+            *node = match direction {
+                'L' => left,
+                _ => right,
+            };
+
+            Some(*node)
+        })
+        .count()
+}
+
+fn parse_map(input: &str) -> Result<Map> {
+    let mut lines = parse::lines(input);
+    let instructions = parse::next_line(&mut lines, input, "a list of instructions")?;
+
+    if let Some(i) = instructions.find(|c| c != 'L' && c != 'R') {
+        let span = parse::char_span(instructions, i);
+        return Err(ParseError::new(span, "expected an instruction, L or R"));
+    }
+
+    if instructions.is_empty() {
+        return Err(ParseError::new(
+            instructions,
+            "expected at least one instruction, L or R",
+        ));
+    }
+
+    lines.next();
+
+    let mut nodes = Vec::new();
+
+    for line in lines {
+        let (node, connections) = parse::split_once(line, " = ")?;
+        let connections = parse::strip_prefix(connections, "(")?;
+        let connections = parse::strip_suffix(connections, ")")?;
+        let (left, right) = parse::split_once(connections, ", ")?;
+        nodes.push((node, (left, right)));
+    }
+
+    // Part 2's ghosts start from every node ending in `A`, so there has to be at least one.
+    if !nodes.iter().any(|(node, _)| node.ends_with('A')) {
+        let message = "expected a node ending in `A` to start from";
+        return Err(ParseError::new(parse::end(input), message));
+    }
+
+    // Check where each node leads up front so following the instructions can't get lost.
+    let known_nodes: HashSet<_> = nodes.iter().map(|(node, _)| *node).collect();
+
+    for (_, (left, right)) in &nodes {
+        for next_node in [left, right] {
+            if !known_nodes.contains(next_node) {
+                let message = format!("node `{next_node}` isn't in the network");
+                return Err(ParseError::new(next_node, message));
+            }
+        }
+    }
+
+    let network = nodes
+        .into_iter()
+        .map(|(node, (left, right))| (node.to_string(), (left.to_string(), right.to_string())))
+        .collect();
+
+    Ok(Map {
+        instructions: instructions.to_string(),
+        network,
+    })
+}
+
+/// A network where each ghost follows the instructions around a loop of its own, reaching its end
+/// node once per lap, as in the real puzzle. Any other way out of a node leads somewhere random.
+/// Laps are the number of instructions times a different prime for each ghost, so the ghosts do
+/// all line up eventually. The size is the number of instructions.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    const LAP_MULTIPLES: [usize; 6] = [43, 47, 53, 59, 61, 67];
+
+    let instructions: Vec<_> = (0..options.size.max(1))
+        .map(|_| *rng.choose(&['L', 'R']))
+        .collect();
+
+    let mut links = Vec::new(); // node, the next node, and which way the instructions go to it
+
+    for (ghost, multiple) in LAP_MULTIPLES.into_iter().enumerate() {
+        let letter = char::from(b'A' + ghost as u8);
+        let start = format!("{letter}{letter}A");
+        let end = if ghost == 0 {
+            "ZZZ".to_string()
+        } else {
+            format!("{letter}{letter}Z")
+        };
+
+        let lap_length = instructions.len() * multiple;
+        let lap: Vec<_> = (0..lap_length - 1)
+            .map(|i| format!("{letter}{i}N"))
+            .chain([end])
+            .collect();
+
+        links.push((start, lap[0].clone(), instructions[0]));
+
+        for i in 0..lap_length {
+            let next = lap[(i + 1) % lap_length].clone();
+            links.push((
+                lap[i].clone(),
+                next,
+                instructions[(i + 1) % instructions.len()],
+            ));
+        }
+    }
+
+    let nodes: Vec<_> = links.iter().map(|(node, _, _)| node.clone()).collect();
+    let mut lines: Vec<_> = links
+        .iter()
+        .map(|(node, next, direction)| {
+            let elsewhere = rng.choose(&nodes);
+
+            match direction {
+                'L' => format!("{node} = ({next}, {elsewhere})"),
+                _ => format!("{node} = ({elsewhere}, {next})"),
+            }
+        })
+        .collect();
+
+    rng.shuffle(&mut lines);
+    let instructions: String = instructions.into_iter().collect();
+    format!("{instructions}\n\n{}\n", lines.join("\n"))
+}
+
+#[test]
+fn malformed() {
+    let input = "LR\n\nBBB = (CCZ, CCZ)\nCCZ = (BBB, BBB)\n";
+    let error = aoc::solution::parse::<Day08>(input).unwrap_err();
+    assert_eq!(
+        error.message(),
+        "expected a node ending in `A` to start from"
+    );
+
+    let error = aoc::solution::parse::<Day08>("\n\nAAA = (AAA, AAA)\n").unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!(error.message(), "expected at least one instruction, L or R");
+    assert_eq!((location.line, location.column), (1, 1));
+
+    let map = Day08::parse("LR\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n").unwrap();
+    let error = Day08::part_1(&map).unwrap_err();
+    assert_eq!(error.message(), "expected a node `AAA` to start from");
+    assert_eq!(Day08::part_2(&map).unwrap(), 1);
+}
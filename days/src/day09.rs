@@ -0,0 +1,177 @@
+use aoc::generate::{Options, Rng};
+use aoc::solution::{Print, Streaming};
+use aoc::{parse, ParseError, Result, Solution};
+use std::iter;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Vec<isize>>;
+    type Answer = isize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse::lines(input).map(parse_history).collect()
+    }
+
+    fn part_1(report: &Self::Parsed) -> Result<isize> {
+        report
+            .iter()
+            .map(|history| extrapolate_next_value(history))
+            .sum()
+    }
+
+    fn part_2(report: &Self::Parsed) -> Result<isize> {
+        report
+            .iter()
+            .map(|history| extrapolate_prev_value(history))
+            .sum()
+    }
+}
+
+impl Print for Day09 {
+    fn print(report: &Self::Parsed) -> String {
+        report
+            .iter()
+            .map(|history| {
+                let values: Vec<_> = history.iter().map(|value| value.to_string()).collect();
+                values.join(" ") + "\n"
+            })
+            .collect()
+    }
+}
+
+impl Streaming for Day09 {
+    type Record = Vec<isize>;
+    type Totals = isize;
+
+    fn parse_line(line: &str) -> Result<Self::Record> {
+        parse_history(line)
+    }
+
+    fn add_1(total: &mut isize, history: &Vec<isize>) -> Result<()> {
+        *total += extrapolate_next_value(history)?;
+
+        Ok(())
+    }
+
+    fn add_2(total: &mut isize, history: &Vec<isize>) -> Result<()> {
+        *total += extrapolate_prev_value(history)?;
+
+        Ok(())
+    }
+
+    fn finish(total: isize) -> Result<isize> {
+        Ok(total)
+    }
+}
+
+fn calculate_next_sequence(sequence: &[isize]) -> Vec<isize> {
+    sequence.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+/// The history followed by each round of differences, down to the round that's all zeros. Each
+/// round is a value shorter, so if none is all zeros the differences run out first.
+fn create_sequences(history: &[isize]) -> Result<Vec<Vec<isize>>> {
+    let sequences: Vec<_> = iter::successors(Some(history.to_owned()), |sequence| {
+        if sequence.iter().all(|&value| value == 0) {
+            return None;
+        }
+
+        Some(calculate_next_sequence(sequence))
+    })
+    .collect();
+
+    if sequences.last().is_some_and(|sequence| sequence.is_empty()) {
+        let message = "the differences run out before they're all zeros";
+        return Err(ParseError::new("", message));
+    }
+
+    Ok(sequences)
+}
+
+/// Extending every round of differences by one from the bottom up adds up their last values.
+fn extrapolate_next_value(history: &[isize]) -> Result<isize> {
+    let sequences = create_sequences(history)?;
+    Ok(sequences
+        .iter()
+        .filter_map(|sequence| sequence.last())
+        .sum())
+}
+
+/// Extending every round backwards instead takes each first value away from the one above, which
+/// alternates their signs.
+fn extrapolate_prev_value(history: &[isize]) -> Result<isize> {
+    let sequences = create_sequences(history)?;
+
+    Ok(sequences
+        .iter()
+        .filter_map(|sequence| sequence.first())
+        .rev()
+        .fold(0, |below, first| first - below))
+}
+
+fn parse_history(line: &str) -> Result<Vec<isize>> {
+    let history = parse::numbers(line)?;
+
+    if history.is_empty() {
+        return Err(ParseError::new(line, "expected a history of values"));
+    }
+
+    // Checked now so the error can point at the line.
+    create_sequences(&history).map_err(|error| ParseError::new(line, error.message()))?;
+    Ok(history)
+}
+
+/// Histories of twenty-one values of polynomials up to the fifth degree, so it takes at most six
+/// rounds of differences to reach all zeroes. The size is the number of histories.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let mut input = String::new();
+
+    for _ in 0..options.size {
+        let coefficients: Vec<_> = (0..rng.range(1..7))
+            .map(|_| rng.range(0..7) as isize - 3)
+            .collect();
+
+        let values: Vec<_> = (0..21)
+            .map(|x| {
+                let value = coefficients.iter().rev().fold(0, |value, c| value * x + c);
+                value.to_string()
+            })
+            .collect();
+
+        input += &values.join(" ");
+        input.push('\n');
+    }
+
+    input
+}
+
+#[test]
+fn malformed_histories() {
+    let cases = [
+        ("5\n", "the differences run out before they're all zeros"),
+        (
+            "1 2 4\n",
+            "the differences run out before they're all zeros",
+        ),
+        ("1 2 3\n\n", "expected a history of values"),
+    ];
+
+    for (input, message) in cases {
+        let error = aoc::solution::parse::<Day09>(input).unwrap_err();
+        assert_eq!(error.message(), message);
+        assert!(error.location().is_some());
+
+        let streamed = aoc::solution::run_stream::<Day09>(&mut input.as_bytes(), &[1, 2]);
+        assert_eq!(
+            streamed.err().map(|error| error.to_string()),
+            Some(error.to_string())
+        );
+    }
+
+    let report = Day09::parse("0 3 6 9 12 15\n10 13 16 21 30 45\n").unwrap();
+    assert_eq!(Day09::part_1(&report).unwrap(), 18 + 68);
+    assert_eq!(Day09::part_2(&report).unwrap(), -3 + 5);
+}
@@ -0,0 +1,356 @@
+use aoc::generate::{Options, Rng};
+use aoc::grid::{self, Cell, Direction, Grid, DIR_E, DIR_N, DIR_S, DIR_W};
+use aoc::render::Scene;
+use aoc::search::{self, Reached};
+use aoc::solution::{Print, Render};
+use aoc::{parse, ParseError, Result, Solution};
+
+// As you move in a given direction, which tiles connect with the tile you're on?
+const CONNECTING_TILES_N: [char; 4] = ['S', '|', 'F', '7'];
+const CONNECTING_TILES_S: [char; 4] = ['S', '|', 'L', 'J'];
+const CONNECTING_TILES_E: [char; 4] = ['S', '-', 'J', '7'];
+const CONNECTING_TILES_W: [char; 4] = ['S', '-', 'L', 'F'];
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Grid;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let grid = grid::parse_grid_of(input, "|-LJ7F.S")?;
+        let mut start_indices = input.match_indices('S').map(|(i, _)| i);
+
+        if start_indices.next().is_none() {
+            return Err(ParseError::new(input, "expected a start tile `S`"));
+        }
+
+        if let Some(i) = start_indices.next() {
+            return Err(ParseError::new(
+                &input[i..i + 1],
+                "expected only one start tile",
+            ));
+        }
+
+        check_loop(input, &grid)?;
+        Ok(grid)
+    }
+
+    fn part_1(grid: &Self::Parsed) -> Result<usize> {
+        // Exploring both ways around the loop at once, the farthest point is the last one reached.
+        Ok(get_loop(grid).iter().map(|(_, steps)| steps).max().unwrap())
+    }
+
+    fn part_2(grid: &Self::Parsed) -> Result<usize> {
+        Ok(count_enclosed_tiles(grid))
+    }
+}
+
+impl Print for Day10 {
+    fn print(grid: &Self::Parsed) -> String {
+        grid.to_string()
+    }
+}
+
+impl Render for Day10 {
+    fn render(grid: &Self::Parsed) -> Scene {
+        let loop_cells = get_loop(grid);
+        let enclosed = find_enclosed_cells(grid);
+
+        let outside = grid
+            .positions()
+            .filter(|cell| !loop_cells.contains(cell) && !enclosed.contains(cell));
+
+        Scene::new(grid.clone())
+            .with_layer("outside", outside)
+            .with_layer("path", loop_cells.iter().map(|(&cell, _)| cell))
+            .with_layer("inside", enclosed)
+    }
+}
+
+fn count_enclosed_tiles(grid: &Grid) -> usize {
+    find_enclosed_cells(grid).len()
+}
+
+fn find_enclosed_cells(grid: &Grid) -> Vec<Cell> {
+    let loop_cells = get_loop(grid);
+
+    // Insight: To determine whether a cell is inside the loop, we can scan each row left-to-right.
+    // The first path cell we find that connects to the one above it is an exterior wall. All cells
+    // after it are inside the loop. The next time we find a path cell that connects to the one
+    // above it, that's the matching exterior wall, and all subsequent cells are outside the loop.
+    // There may be multiple pairs of matching walls in a row.
+
+    let mut result = Vec::new();
+
+    for row in 0..grid.row_count() {
+        let mut is_inside = false;
+
+        for col in 0..grid.col_count() {
+            let cell = (row, col);
+
+            if loop_cells.contains(&cell) {
+                if does_cell_above_connect(grid, cell) {
+                    is_inside = !is_inside;
+                }
+
+                continue;
+            }
+
+            if is_inside {
+                result.push(cell);
+            }
+        }
+    }
+
+    result
+}
+
+fn does_cell_above_connect(grid: &Grid, cell: Cell) -> bool {
+    let tile = grid[cell];
+
+    CONNECTING_TILES_S.contains(&tile)
+        && get_adjacent_cell_if_connected(grid, cell, DIR_N, &CONNECTING_TILES_N).is_some()
+}
+
+fn get_adjacent_cell_if_connected(
+    grid: &Grid,
+    cell: Cell,
+    direction: Direction,
+    connecting_tiles: &[char],
+) -> Option<Cell> {
+    if let Some(adjacent_cell) = grid.adjacent_cell(cell, direction) {
+        if connecting_tiles.contains(&grid[adjacent_cell]) {
+            return Some(adjacent_cell);
+        }
+    }
+
+    None
+}
+
+/// The ways a pipe leads out of its tile, besides the start tile's, which could be any.
+fn pipe_directions(tile: char) -> &'static [Direction] {
+    match tile {
+        '|' => &[DIR_N, DIR_S],
+        '-' => &[DIR_E, DIR_W],
+        'L' => &[DIR_N, DIR_E],
+        'J' => &[DIR_N, DIR_W],
+        '7' => &[DIR_S, DIR_W],
+        'F' => &[DIR_S, DIR_E],
+        _ => &[],
+    }
+}
+
+fn get_connected_cells(grid: &Grid, cell: Cell) -> Vec<Cell> {
+    match grid[cell] {
+        'S' => [
+            (DIR_N, CONNECTING_TILES_N),
+            (DIR_S, CONNECTING_TILES_S),
+            (DIR_E, CONNECTING_TILES_E),
+            (DIR_W, CONNECTING_TILES_W),
+        ]
+        .iter()
+        .filter_map(|(direction, connecting_tiles)| {
+            get_adjacent_cell_if_connected(grid, cell, *direction, connecting_tiles)
+        })
+        .collect(),
+        tile => pipe_directions(tile)
+            .iter()
+            .filter_map(|&direction| grid.adjacent_cell(cell, direction))
+            .collect(),
+    }
+}
+
+/// Follows the pipe from the start tile round to make sure it's a loop: the start tile has to
+/// connect to exactly two pipes, and each pipe along the way has to lead to one that connects back
+/// to it, without running off the grid.
+fn check_loop(input: &str, grid: &Grid) -> Result<()> {
+    // The tiles are all ASCII, so a tile's column is also its byte offset in the row.
+    let rows: Vec<_> = parse::lines(input).collect();
+    let span = |(row, col): Cell| &rows[row][col..col + 1];
+
+    let start = get_start_cell(grid);
+    let start_connections = get_connected_cells(grid, start);
+
+    if start_connections.len() != 2 {
+        let message = format!(
+            "expected the start tile to connect to two pipes, found {}",
+            start_connections.len()
+        );
+        return Err(ParseError::new(span(start), message));
+    }
+
+    let (mut previous, mut cell) = (start, start_connections[0]);
+
+    while cell != start {
+        let connections = get_connected_cells(grid, cell);
+
+        if connections.len() < 2 {
+            let message = format!("pipe `{}` leads off the grid", grid[cell]);
+            return Err(ParseError::new(span(cell), message));
+        }
+
+        let next = connections.into_iter().find(|&next| next != previous);
+        let next = next.expect("a pipe leads two different ways");
+
+        if !get_connected_cells(grid, next).contains(&cell) {
+            let message = format!("expected a pipe connecting back to the `{}`", grid[cell]);
+            return Err(ParseError::new(span(next), message));
+        }
+
+        (previous, cell) = (cell, next);
+    }
+
+    Ok(())
+}
+
+fn get_loop(grid: &Grid) -> Reached<Cell> {
+    search::reachable(get_start_cell(grid), |&cell| {
+        get_connected_cells(grid, cell)
+    })
+}
+
+fn get_start_cell(grid: &Grid) -> Cell {
+    grid.position(|&tile| tile == 'S').unwrap()
+}
+
+/// A field with a single loop of pipe through the start tile, among junk pipes that aren't part of
+/// it. The loop traces the outline of a random blob, so it encloses plenty of tiles. The size is
+/// the length of each side, and the density is how much of the field the blob fills (by default
+/// half).
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let side = options.size.max(3);
+
+    // The blob is made of squares whose corners are the tiles, so it's one smaller each way.
+    let blob = generate_blob(rng, side - 1, options.density_or(0.5));
+    let is_in_blob = |row: usize, col: usize, row_offset: usize, col_offset: usize| {
+        let cell = (row.wrapping_sub(row_offset), col.wrapping_sub(col_offset));
+        blob.get(cell) == Some(&true)
+    };
+
+    let mut grid = Grid::filled(side, side, '.');
+    let mut loop_cells = Vec::new();
+
+    for (row, col) in grid.positions().collect::<Vec<_>>() {
+        // Each way out of a corner runs along the outline if it has the blob on only one side.
+        let n = is_in_blob(row, col, 1, 1) != is_in_blob(row, col, 1, 0);
+        let s = is_in_blob(row, col, 0, 1) != is_in_blob(row, col, 0, 0);
+        let e = is_in_blob(row, col, 1, 0) != is_in_blob(row, col, 0, 0);
+        let w = is_in_blob(row, col, 1, 1) != is_in_blob(row, col, 0, 1);
+
+        grid[(row, col)] = match (n, s, e, w) {
+            (true, true, false, false) => '|',
+            (false, false, true, true) => '-',
+            (true, false, true, false) => 'L',
+            (true, false, false, true) => 'J',
+            (false, true, false, true) => '7',
+            (false, true, true, false) => 'F',
+            _ if rng.chance(0.5) => '.',
+            _ => *rng.choose(&['|', '-', 'L', 'J', '7', 'F']),
+        };
+
+        if n || s || e || w {
+            loop_cells.push((row, col));
+        }
+    }
+
+    // Junk next to the start could look like it connects, so clear it away.
+    let start = *rng.choose(&loop_cells);
+    let neighbours: Vec<_> = grid.neighbours_4(start).collect();
+
+    for cell in neighbours {
+        if !loop_cells.contains(&cell) {
+            grid[cell] = '.';
+        }
+    }
+
+    grid[start] = 'S';
+    grid.to_string()
+}
+
+/// Grows a random blob of squares out from the middle of an `n` by `n` field. It never leaves a hole
+/// or lets two squares touch only at their corners, so its outline is always a single loop.
+fn generate_blob(rng: &mut Rng, n: usize, fill: f64) -> Grid<bool> {
+    let mut blob = Grid::filled(n, n, false);
+    let target = ((n * n) as f64 * fill).max(1.0) as usize;
+    let mut frontier = vec![(n / 2, n / 2)];
+    let mut size = 0;
+
+    while size < target && !frontier.is_empty() {
+        let cell = frontier.swap_remove(rng.range(0..frontier.len()));
+
+        if blob[cell] || !can_grow_into(&blob, cell) {
+            continue;
+        }
+
+        blob[cell] = true;
+        size += 1;
+        frontier.extend(blob.neighbours_4(cell).filter(|&next| !blob[next]));
+    }
+
+    blob
+}
+
+fn can_grow_into(blob: &Grid<bool>, (row, col): Cell) -> bool {
+    // The eight squares around the cell, clockwise from the top left.
+    let ring = [
+        (0, 0),
+        (0, 1),
+        (0, 2),
+        (1, 2),
+        (2, 2),
+        (2, 1),
+        (2, 0),
+        (1, 0),
+    ]
+    .map(|(row_offset, col_offset)| {
+        let cell = (
+            (row + row_offset).wrapping_sub(1),
+            (col + col_offset).wrapping_sub(1),
+        );
+        blob.get(cell) == Some(&true)
+    });
+
+    // Touching a square diagonally with neither square between them would pinch the outline.
+    let pinches = [0, 2, 4, 6]
+        .into_iter()
+        .any(|corner| ring[corner] && !ring[(corner + 7) % 8] && !ring[corner + 1]);
+
+    // Joining up separate stretches of the blob around the cell would enclose a hole.
+    let stretches = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+
+    !pinches && stretches <= 1
+}
+
+#[test]
+fn broken_loops() {
+    let cases = [
+        (
+            "S-.\n...\n",
+            (1, 1),
+            "expected the start tile to connect to two pipes, found 1",
+        ),
+        (
+            ".|.\n.S.\n...\n",
+            (2, 2),
+            "expected the start tile to connect to two pipes, found 1",
+        ),
+        ("S-\n||\n", (2, 1), "pipe `|` leads off the grid"),
+        (
+            "S-\n|.\n..\n",
+            (3, 1),
+            "expected a pipe connecting back to the `|`",
+        ),
+    ];
+
+    for (input, (line, column), message) in cases {
+        let error = aoc::solution::parse::<Day10>(input).unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!(error.message(), message);
+        assert_eq!((location.line, location.column), (line, column), "{input}");
+    }
+
+    assert!(aoc::solution::parse::<Day10>("S7\nLJ\n").is_ok());
+}
@@ -0,0 +1,162 @@
+use aoc::generate::{Options, Rng};
+use aoc::grid::{self, Cell, Grid};
+use aoc::render::Scene;
+use aoc::solution::{Print, Render};
+use aoc::{Result, Solution};
+use std::collections::HashSet;
+
+pub type Image = Grid<bool>; // whether each cell holds a galaxy
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed = Image;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        grid::parse_grid_with(input, ".#", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+
+    fn part_1(image: &Self::Parsed) -> Result<usize> {
+        Ok(sum_path_lengths_between_galaxies(image, 2))
+    }
+
+    fn part_2(image: &Self::Parsed) -> Result<usize> {
+        Ok(sum_path_lengths_between_galaxies(image, 1_000_000))
+    }
+}
+
+impl Print for Day11 {
+    fn print(image: &Self::Parsed) -> String {
+        image
+            .map(|&galaxy| if galaxy { '#' } else { '.' })
+            .to_string()
+    }
+}
+
+impl Render for Day11 {
+    fn render(image: &Self::Parsed) -> Scene {
+        // Rows and columns without galaxies are the ones that expand.
+        let (rows, cols) = get_lines_without_galaxies(image);
+        let expanded = image
+            .positions()
+            .filter(|(row, col)| rows.contains(row) || cols.contains(col));
+
+        let tiles = image.map(|&galaxy| if galaxy { '#' } else { '.' });
+        Scene::new(tiles).with_layer("expanded", expanded)
+    }
+}
+
+fn calculate_distance_between_cells(
+    (row_a, col_a): Cell,
+    (row_b, col_b): Cell,
+    expansion_factor: usize,
+    rows_without_galaxies: &[usize],
+    cols_without_galaxies: &[usize],
+) -> usize {
+    let row_range = if row_a < row_b {
+        row_a..row_b
+    } else {
+        row_b..row_a
+    };
+
+    let col_range = if col_a < col_b {
+        col_a..col_b
+    } else {
+        col_b..col_a
+    };
+
+    let row_expansion_crossings = rows_without_galaxies
+        .iter()
+        .filter(|row| row_range.contains(row))
+        .count();
+
+    let col_expansion_crossings = cols_without_galaxies
+        .iter()
+        .filter(|col| col_range.contains(col))
+        .count();
+
+    let row_distance = row_a.abs_diff(row_b) + row_expansion_crossings * (expansion_factor - 1);
+    let col_distance = col_a.abs_diff(col_b) + col_expansion_crossings * (expansion_factor - 1);
+    row_distance + col_distance
+}
+
+fn get_galaxy_cells(image: &Image) -> Vec<Cell> {
+    image
+        .iter()
+        .filter(|(_, &is_galaxy)| is_galaxy)
+        .map(|(cell, _)| cell)
+        .collect()
+}
+
+fn get_galaxy_pairs(image: &Image) -> HashSet<(Cell, Cell)> {
+    get_galaxy_cells(image)
+        .into_iter()
+        .flat_map(|cell_a| {
+            get_galaxy_cells(image)
+                .into_iter()
+                .map(move |cell_b| {
+                    // Sort so that collecting to a set removes duplicates.
+                    if cell_a < cell_b {
+                        (cell_a, cell_b)
+                    } else {
+                        (cell_b, cell_a)
+                    }
+                })
+                .filter(|(cell_a, cell_b)| cell_a != cell_b)
+        })
+        .collect()
+}
+
+fn get_lines_without_galaxies(image: &Image) -> (Vec<usize>, Vec<usize>) {
+    let rows_without_galaxies = (0..image.row_count())
+        .filter(|&row| !image.row(row).contains(&true))
+        .collect();
+
+    let cols_without_galaxies = (0..image.col_count())
+        .filter(|&col| !image.column(col).any(|&is_galaxy| is_galaxy))
+        .collect();
+
+    (rows_without_galaxies, cols_without_galaxies)
+}
+
+fn sum_path_lengths_between_galaxies(image: &Image, expansion_factor: usize) -> usize {
+    let (rows_without_galaxies, cols_without_galaxies) = get_lines_without_galaxies(image);
+
+    get_galaxy_pairs(image)
+        .into_iter()
+        .map(|(cell_a, cell_b)| {
+            calculate_distance_between_cells(
+                cell_a,
+                cell_b,
+                expansion_factor,
+                &rows_without_galaxies,
+                &cols_without_galaxies,
+            )
+        })
+        .sum()
+}
+
+/// A square image with galaxies scattered across it. The size is the length of each side, and the
+/// density is the chance of each cell holding a galaxy (by default 0.03).
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let side = options.size.max(1);
+    let density = options.density_or(0.03);
+    let image = Grid::filled(side, side, ()).map(|_| if rng.chance(density) { '#' } else { '.' });
+    image.to_string()
+}
+
+#[test]
+fn sample() {
+    let input = include_str!("../../examples/day11/sample.txt");
+    let image = Day11::parse(input).unwrap();
+    assert_eq!(sum_path_lengths_between_galaxies(&image, 2), 374);
+    assert_eq!(sum_path_lengths_between_galaxies(&image, 10), 1030);
+    assert_eq!(sum_path_lengths_between_galaxies(&image, 100), 8410);
+}
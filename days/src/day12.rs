@@ -0,0 +1,299 @@
+use aoc::generate::{Options, Rng};
+use aoc::solution::{Print, Streaming};
+use aoc::{oracle, pool};
+use aoc::{parse, ParseError, Result, Solution};
+use std::ops::Range;
+
+pub type Record = (Vec<char>, Vec<usize>); // conditions, damaged group sizes
+
+const DAMAGED: char = '#';
+const OPERATIONAL: char = '.';
+const UNKNOWN: char = '?';
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed = Vec<Record>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse::lines(input).map(parse_record).collect()
+    }
+
+    fn part_1(records: &Self::Parsed) -> Result<usize> {
+        // Every record is counted on its own, so they can be counted side by side.
+        Ok(pool::map(records, count_arrangements).into_iter().sum())
+    }
+
+    fn part_2(_records: &Self::Parsed) -> Result<usize> {
+        // TODO
+        Ok(0)
+    }
+}
+
+impl Print for Day12 {
+    fn print(records: &Self::Parsed) -> String {
+        records
+            .iter()
+            .map(|(conditions, damaged_group_sizes)| {
+                let conditions: String = conditions.iter().collect();
+                let sizes: Vec<_> = damaged_group_sizes
+                    .iter()
+                    .map(|size| size.to_string())
+                    .collect();
+                format!("{conditions} {}\n", sizes.join(","))
+            })
+            .collect()
+    }
+}
+
+impl Streaming for Day12 {
+    type Record = Record;
+    type Totals = usize;
+
+    fn parse_line(line: &str) -> Result<Self::Record> {
+        parse_record(line)
+    }
+
+    fn add_1(total: &mut usize, record: &Record) -> Result<()> {
+        *total += count_arrangements(record);
+
+        Ok(())
+    }
+
+    fn add_2(_total: &mut usize, _record: &Record) -> Result<()> {
+        // TODO
+        Ok(())
+    }
+
+    fn finish(total: usize) -> Result<usize> {
+        Ok(total)
+    }
+}
+
+fn count_arrangements((conditions, damaged_group_sizes): &Record) -> usize {
+    let (n, m) = (conditions.len(), damaged_group_sizes.len());
+
+    // counts[i][g] is how many ways the springs from i on can be arranged into the groups from g
+    // on. There's an extra row past the end for a group that ends at the last spring, since the
+    // next group starts a spring after a group ends, to leave room for the operational one between.
+    let mut counts = vec![vec![0; m + 1]; n + 2];
+    counts[n][m] = 1;
+    counts[n + 1][m] = 1;
+
+    for i in (0..n).rev() {
+        for g in 0..=m {
+            if conditions[i] != DAMAGED {
+                counts[i][g] += counts[i + 1][g];
+            }
+
+            if conditions[i] != OPERATIONAL && g < m {
+                let end = i + damaged_group_sizes[g];
+                let fits = end <= n
+                    && !conditions[i..end].contains(&OPERATIONAL)
+                    && conditions.get(end) != Some(&DAMAGED);
+
+                if fits {
+                    counts[i][g] += counts[(end + 1).min(n + 1)][g + 1];
+                }
+            }
+        }
+    }
+
+    counts[0][0]
+}
+
+/// The slow but obvious way, trying every way of filling in the unknown conditions. This is only
+/// kept as a reference to check the real one against.
+fn calculate_possible_arrangements((conditions, damaged_group_sizes): &Record) -> usize {
+    let known_damaged_count = conditions.iter().filter(|&c| *c == DAMAGED).count();
+    let unknown_count = conditions.iter().filter(|&c| *c == UNKNOWN).count();
+    let total_spring_count: usize = damaged_group_sizes.iter().sum();
+
+    let Some(unknown_damaged_count) = total_spring_count.checked_sub(known_damaged_count) else {
+        return 0;
+    };
+
+    get_unknowns_permutations(unknown_count, unknown_damaged_count)
+        .iter()
+        .map(|unknowns_permutation| create_arrangement(conditions, unknowns_permutation))
+        .filter(|arrangement| is_arrangement_valid(arrangement, damaged_group_sizes))
+        .count()
+}
+
+fn count_repeated_values(values: &[char]) -> Vec<(char, usize)> {
+    values.iter().fold(vec![], |mut counts, value| {
+        if let Some((previous_value, repeat_count)) = counts.last_mut() {
+            if value == previous_value {
+                *repeat_count += 1;
+            } else {
+                counts.push((*value, 1));
+            }
+        } else {
+            counts.push((*value, 1));
+        }
+
+        counts
+    })
+}
+
+fn create_arrangement(conditions: &[char], unknowns_permutation: &[char]) -> Vec<char> {
+    let mut unknowns_permutation = unknowns_permutation.iter();
+
+    conditions
+        .iter()
+        .map(|&c| match c {
+            UNKNOWN => *unknowns_permutation.next().unwrap(),
+            c => c,
+        })
+        .collect()
+}
+
+fn get_unknowns_permutations(
+    unknown_count: usize,
+    required_damaged_count: usize,
+) -> Vec<Vec<char>> {
+    if required_damaged_count > unknown_count {
+        return vec![];
+    }
+
+    if unknown_count == 0 {
+        return vec![vec![]];
+    }
+
+    if required_damaged_count == 0 {
+        return vec![vec![OPERATIONAL; unknown_count]];
+    }
+
+    let mut result = Vec::new();
+
+    for mut damaged in get_unknowns_permutations(unknown_count - 1, required_damaged_count - 1) {
+        damaged.push(DAMAGED);
+        result.push(damaged);
+    }
+
+    for mut operational in get_unknowns_permutations(unknown_count - 1, required_damaged_count) {
+        operational.push(OPERATIONAL);
+        result.push(operational);
+    }
+
+    result
+}
+
+fn is_arrangement_valid(arrangement: &[char], damaged_group_sizes: &[usize]) -> bool {
+    let arrangement_damaged_group_sizes: Vec<_> = count_repeated_values(arrangement)
+        .into_iter()
+        .filter(|(value, _)| *value == DAMAGED)
+        .map(|(_, repeat_count)| repeat_count)
+        .collect();
+
+    arrangement_damaged_group_sizes == damaged_group_sizes
+}
+
+fn parse_record(line: &str) -> Result<Record> {
+    let (conditions_str, damaged_group_sizes_str) = parse::split_once(line, " ")?;
+
+    let conditions = conditions_str
+        .char_indices()
+        .map(|(i, c)| match c {
+            DAMAGED | OPERATIONAL | UNKNOWN => Ok(c),
+            _ => {
+                let message = format!("unknown spring condition `{c}`");
+                Err(ParseError::new(
+                    parse::char_span(conditions_str, i),
+                    message,
+                ))
+            }
+        })
+        .collect::<Result<_>>()?;
+
+    let damaged_group_sizes = damaged_group_sizes_str
+        .split(',')
+        .map(parse::number)
+        .collect::<Result<_>>()?;
+
+    Ok((conditions, damaged_group_sizes))
+}
+
+/// Records of up to twenty springs each, with some of their conditions unknown. The size is the
+/// number of records, and the density is the chance of each spring's condition being unknown (by
+/// default 0.35).
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let density = options.density_or(0.35);
+    let mut input = String::new();
+
+    for _ in 0..options.size {
+        let length = rng.range(4..21);
+        let mut conditions: Vec<_> = (0..length)
+            .map(|_| {
+                if rng.chance(0.4) {
+                    DAMAGED
+                } else {
+                    OPERATIONAL
+                }
+            })
+            .collect();
+
+        // Every record has at least one group of damaged springs.
+        let i = rng.range(0..length);
+        conditions[i] = DAMAGED;
+
+        let damaged_group_sizes: Vec<_> = count_repeated_values(&conditions)
+            .into_iter()
+            .filter(|(value, _)| *value == DAMAGED)
+            .map(|(_, repeat_count)| repeat_count.to_string())
+            .collect();
+
+        let conditions: String = conditions
+            .into_iter()
+            .map(|c| if rng.chance(density) { UNKNOWN } else { c })
+            .collect();
+
+        input += &format!("{conditions} {}\n", damaged_group_sizes.join(","));
+    }
+
+    input
+}
+
+/// Checks the part 1 count of arrangements against trying every one, on generated records.
+pub fn cross_check(seeds: Range<u64>, options: &Options) -> std::result::Result<(), String> {
+    let total = |input: &str, count: fn(&Record) -> usize| {
+        // Minimising can leave a group of no damaged springs, which real records never have.
+        let records = Day12::parse(input).ok()?;
+        let has_empty_group = records.iter().any(|(_, sizes)| sizes.contains(&0));
+        (!has_empty_group).then(|| records.iter().map(count).sum::<usize>())
+    };
+
+    oracle::cross_check(
+        seeds,
+        |rng| generate(rng, options),
+        |input| total(input, calculate_possible_arrangements),
+        |input| total(input, count_arrangements),
+    )
+    .map_err(|mismatch| mismatch.to_string())
+}
+
+#[test]
+fn sample() {
+    [
+        ("???.### 1,1,3", 1),
+        (".??..??...?##. 1,1,3", 4),
+        ("?#?#?#?#?#?#?#? 1,3,1,6", 1),
+        ("????.#...#... 4,1,1", 1),
+        ("????.######..#####. 1,6,5", 4),
+        ("?###???????? 3,2,1", 10),
+    ]
+    .iter()
+    .for_each(|(line, expected)| {
+        let record = parse_record(line).unwrap();
+        assert_eq!(calculate_possible_arrangements(&record), *expected);
+        assert_eq!(count_arrangements(&record), *expected);
+    });
+}
+
+#[test]
+fn reference() {
+    assert_eq!(cross_check(0..20, &Options::new(10)), Ok(()));
+}
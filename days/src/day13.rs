@@ -0,0 +1,238 @@
+use aoc::generate::{Options, Rng};
+use aoc::grid::{self, Grid};
+use aoc::solution::Print;
+use aoc::{explain, parse, Result, Solution};
+use std::iter;
+
+pub type Pattern = Grid;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed = Vec<Pattern>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_patterns(input)
+    }
+
+    fn part_1(patterns: &Self::Parsed) -> Result<usize> {
+        Ok(summarize_patterns(patterns, false))
+    }
+
+    fn part_2(patterns: &Self::Parsed) -> Result<usize> {
+        Ok(summarize_patterns(patterns, true))
+    }
+}
+
+impl Print for Day13 {
+    fn print(patterns: &Self::Parsed) -> String {
+        let patterns: Vec<_> = patterns.iter().map(|pattern| pattern.to_string()).collect();
+        patterns.join("\n")
+    }
+}
+
+fn count_reflection_diffs(pattern: &Pattern, index: usize) -> usize {
+    pattern
+        .rows()
+        .map(|values| {
+            let mut left = values.to_vec();
+            let right = left.split_off(index);
+            left.reverse();
+            iter::zip(left, right).filter(|(a, b)| a != b).count()
+        })
+        .sum()
+}
+
+fn find_reflection_index(pattern: &Pattern, allowed_diffs: usize) -> Option<usize> {
+    let end_index = pattern.col_count();
+    (1..end_index).find(|index| count_reflection_diffs(pattern, *index) == allowed_diffs)
+}
+
+fn parse_patterns(input: &str) -> Result<Vec<Pattern>> {
+    let mut lines = parse::lines(input).peekable();
+    let mut result = Vec::new();
+
+    while lines.peek().is_some() {
+        let rows: Vec<_> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+
+        if !rows.is_empty() {
+            result.push(grid::parse_rows(&rows, Some(".#"))?);
+        }
+    }
+
+    Ok(result)
+}
+
+fn summarize_patterns(patterns: &[Pattern], allow_smudges: bool) -> usize {
+    let allowed_reflection_diffs = if allow_smudges { 1 } else { 0 };
+
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(i, pattern)| {
+            let cols_left_of_vertical_reflection =
+                find_reflection_index(pattern, allowed_reflection_diffs);
+            let rows_above_horizontal_reflection =
+                find_reflection_index(&pattern.transpose(), allowed_reflection_diffs);
+
+            let summary = cols_left_of_vertical_reflection.unwrap_or(0)
+                + (100 * rows_above_horizontal_reflection.unwrap_or(0));
+
+            explain!(
+                pattern = i + 1,
+                reflection = describe_reflection(
+                    cols_left_of_vertical_reflection,
+                    rows_above_horizontal_reflection
+                ),
+                summary = summary,
+            );
+
+            summary
+        })
+        .sum()
+}
+
+fn describe_reflection(cols_left: Option<usize>, rows_above: Option<usize>) -> String {
+    match (cols_left, rows_above) {
+        (Some(cols), Some(rows)) => format!("after column {cols} and row {rows}"),
+        (Some(cols), None) => format!("after column {cols}"),
+        (None, Some(rows)) => format!("after row {rows}"),
+        (None, None) => "none".to_string(),
+    }
+}
+
+/// Patterns that each have one perfect reflection and one other that's perfect but for a single
+/// smudge, as in the real puzzle. The size is the number of patterns.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let patterns: Vec<_> = (0..options.size.max(1))
+        .map(|_| generate_pattern(rng).to_string())
+        .collect();
+
+    patterns.join("\n")
+}
+
+fn generate_pattern(rng: &mut Rng) -> Pattern {
+    loop {
+        let (row_count, col_count) = (rng.range(5..18), rng.range(5..18));
+        let mut pattern = Grid::filled(row_count, col_count, ()).map(|_| *rng.choose(&['.', '#']));
+
+        // Reflect the rows across one line for the smudged reflection and the columns across
+        // another for the perfect one. The columns' line mustn't be in the middle, so some columns
+        // are left out of that reflection for the smudge to go in.
+        let smudged_index = rng.range(1..row_count);
+        let perfect_index = rng.range(1..col_count);
+
+        if perfect_index * 2 == col_count {
+            continue;
+        }
+
+        for row in 0..smudged_index.min(row_count - smudged_index) {
+            for col in 0..col_count {
+                pattern[(smudged_index + row, col)] = pattern[(smudged_index - 1 - row, col)];
+            }
+        }
+
+        for col in 0..perfect_index.min(col_count - perfect_index) {
+            for row in 0..row_count {
+                pattern[(row, perfect_index + col)] = pattern[(row, perfect_index - 1 - col)];
+            }
+        }
+
+        let reflected_rows = smudged_index.min(row_count - smudged_index);
+        let row = rng.range(smudged_index - reflected_rows..smudged_index + reflected_rows);
+        let col = if perfect_index * 2 < col_count {
+            rng.range(perfect_index * 2..col_count)
+        } else {
+            rng.range(0..perfect_index * 2 - col_count)
+        };
+
+        pattern[(row, col)] = if pattern[(row, col)] == '#' { '.' } else { '#' };
+
+        if rng.chance(0.5) {
+            pattern = pattern.transpose();
+        }
+
+        // Other reflections might have turned up by chance, so check there's only one of each.
+        let transposed = pattern.transpose();
+        let diffs: Vec<_> = (1..pattern.col_count())
+            .map(|index| count_reflection_diffs(&pattern, index))
+            .chain(
+                (1..transposed.col_count()).map(|index| count_reflection_diffs(&transposed, index)),
+            )
+            .collect();
+
+        if diffs.iter().filter(|&&diff| diff == 0).count() == 1
+            && diffs.iter().filter(|&&diff| diff == 1).count() == 1
+        {
+            return pattern;
+        }
+    }
+}
+
+#[test]
+fn properties() {
+    use aoc::property;
+
+    let patterns = |rng: &mut Rng| {
+        let size = rng.range(1..4);
+        generate(rng, &Options::new(size))
+    };
+
+    property::assert_holds(
+        "four rotations are the identity",
+        0..50,
+        patterns,
+        |input| {
+            let patterns = parse_patterns(input).ok()?;
+
+            Some(patterns.iter().all(|pattern| {
+                let rotated = (0..4).fold(pattern.clone(), |pattern, _| pattern.rotate_clockwise());
+                rotated == *pattern && pattern.transpose().transpose() == *pattern
+            }))
+        },
+    );
+
+    // Flipping a pattern left to right flips where its reflections are too.
+    property::assert_holds(
+        "mirroring a pattern mirrors its reflections",
+        0..50,
+        patterns,
+        |input| {
+            let patterns = parse_patterns(input).ok()?;
+
+            Some(patterns.iter().all(|pattern| {
+                let flipped = pattern.flip_horizontal();
+                let col_count = pattern.col_count();
+
+                (1..col_count).all(|index| {
+                    count_reflection_diffs(pattern, index)
+                        == count_reflection_diffs(&flipped, col_count - index)
+                })
+            }))
+        },
+    );
+}
+
+#[test]
+fn explained() {
+    aoc::explain::enable();
+
+    let input = include_str!("../../examples/day13/sample.txt");
+    let report = aoc::solution::run::<Day13>(input, &[1, 2]).unwrap();
+    let notes: Vec<_> = report.parts[1]
+        .notes
+        .iter()
+        .map(|note| note.to_string())
+        .collect();
+
+    assert_eq!(
+        notes,
+        [
+            "pattern 1, reflection after row 3, summary 300",
+            "pattern 2, reflection after row 1, summary 100",
+        ]
+    );
+}
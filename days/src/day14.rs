@@ -0,0 +1,281 @@
+use aoc::cycle;
+use aoc::generate::{Options, Rng};
+use aoc::grid::{self, Grid};
+use aoc::render::{Frames, Scene};
+use aoc::solution::{Animate, Print, Render};
+use aoc::{Result, Solution};
+use std::collections::HashSet;
+use std::iter;
+
+pub type Platform = Grid;
+
+const EMPTY: char = '.';
+const ROUND_ROCK: char = 'O';
+const CUBE_ROCK: char = '#';
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Parsed = Platform;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_platform(input)
+    }
+
+    fn part_1(platform: &Self::Parsed) -> Result<usize> {
+        let platform = tilt_north(platform.clone());
+        Ok(calculate_north_support_beam_load(&platform))
+    }
+
+    fn part_2(platform: &Self::Parsed) -> Result<usize> {
+        let platform = cycle(platform.clone(), 1000000000);
+        Ok(calculate_north_support_beam_load(&platform))
+    }
+}
+
+impl Print for Day14 {
+    fn print(platform: &Self::Parsed) -> String {
+        platform.to_string()
+    }
+}
+
+impl Render for Day14 {
+    fn render(platform: &Self::Parsed) -> Scene {
+        // The platform as part 1 leaves it, tilted north.
+        Scene::new(tilt_north(platform.clone()))
+    }
+}
+
+impl Animate for Day14 {
+    fn animate(platform: Self::Parsed) -> Frames {
+        // Every tilt of the spin cycles, stopping once a cycle ends somewhere it's ended before,
+        // since from there it only goes round again.
+        let mut seen = HashSet::new();
+
+        let tilts = iter::successors(Some((platform, 0)), move |(platform, tilts)| {
+            if tilts % 4 == 0 && !seen.insert(platform.clone()) {
+                return None;
+            }
+
+            Some((tilt(platform, tilts % 4), tilts + 1))
+        });
+
+        Box::new(tilts.map(|(platform, _)| Scene::new(platform)))
+    }
+}
+
+fn calculate_north_support_beam_load(platform: &Platform) -> usize {
+    platform
+        .iter()
+        .filter(|(_, &tile)| tile == ROUND_ROCK)
+        .map(|((row, _), _)| platform.row_count() - row)
+        .sum()
+}
+
+fn cycle(platform: Platform, times: usize) -> Platform {
+    // Eventually a spin cycle leaves the platform in a configuration it's been in before, after
+    // which the same configurations come round again and again, so we can skip the rest.
+    cycle::nth(platform, spin_cycle, times)
+}
+
+fn spin_cycle(platform: &Platform) -> Platform {
+    let mut platform = platform.clone();
+
+    // North, west, south, then east.
+    for _ in 0..4 {
+        platform = tilt_north(platform);
+        platform = platform.rotate_clockwise();
+    }
+
+    platform
+}
+
+/// Tilts the platform the given number of quarter turns round from north, so 1 is west.
+fn tilt(platform: &Platform, quarter_turns: usize) -> Platform {
+    let turned =
+        (0..quarter_turns).fold(platform.clone(), |platform, _| platform.rotate_clockwise());
+    let tilted = tilt_north(turned);
+    (0..quarter_turns).fold(tilted, |platform, _| platform.rotate_counterclockwise())
+}
+
+fn parse_platform(input: &str) -> Result<Platform> {
+    grid::parse_grid_of(input, "O#.")
+}
+
+fn tilt_north(mut platform: Platform) -> Platform {
+    for row in 1..platform.row_count() {
+        for col in 0..platform.col_count() {
+            if platform[(row, col)] != ROUND_ROCK {
+                continue;
+            }
+
+            let empty_spaces_above = (0..row)
+                .rev()
+                .take_while(|&r| platform[(r, col)] == EMPTY)
+                .count();
+
+            if empty_spaces_above == 0 {
+                continue;
+            }
+
+            platform[(row - empty_spaces_above, col)] = ROUND_ROCK;
+            platform[(row, col)] = EMPTY;
+        }
+    }
+
+    platform
+}
+
+/// A square platform of rocks. The size is the length of each side, and the density is the chance
+/// of each tile holding a rock (by default 0.3), two thirds of them round.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let side = options.size.max(1);
+    let density = options.density_or(0.3);
+
+    let platform = Grid::filled(side, side, ()).map(|_| match rng.chance(density) {
+        true if rng.chance(2.0 / 3.0) => ROUND_ROCK,
+        true => CUBE_ROCK,
+        false => EMPTY,
+    });
+
+    platform.to_string()
+}
+
+#[test]
+fn sample() {
+    let input = include_str!("../../examples/day14/sample.txt");
+
+    {
+        let platform = parse_platform(input).unwrap();
+        let platform = tilt_north(platform);
+
+        assert_eq!(
+            platform,
+            parse_platform(
+                r"OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#...."
+            )
+            .unwrap()
+        );
+    }
+
+    [
+        (
+            1,
+            r".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....",
+        ),
+        (
+            2,
+            r".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O",
+        ),
+        (
+            3,
+            r".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O",
+        ),
+    ]
+    .iter()
+    .for_each(|(cycle_count, expected_str)| {
+        let platform = parse_platform(input).unwrap();
+        let platform = cycle(platform, *cycle_count);
+        assert_eq!(platform, parse_platform(expected_str).unwrap());
+    });
+}
+
+#[test]
+fn animation() {
+    let input = include_str!("../../examples/day14/sample.txt");
+    let platform = parse_platform(input).unwrap();
+    let frames: Vec<_> = Day14::animate(platform.clone()).collect();
+
+    assert_eq!(frames[0].tiles, platform);
+    assert_eq!(frames[1].tiles, tilt_north(platform.clone()));
+    assert_eq!(frames[8].tiles, cycle(platform, 2));
+
+    // The last frame is where the cycles start repeating.
+    let last = &frames[frames.len() - 1].tiles;
+    assert_eq!((frames.len() - 1) % 4, 0);
+    assert!(frames[..frames.len() - 1]
+        .iter()
+        .any(|frame| &frame.tiles == last));
+}
+
+#[test]
+fn properties() {
+    use aoc::property;
+
+    let platforms = |rng: &mut Rng| {
+        let size = rng.range(1..12);
+        generate(rng, &Options::new(size))
+    };
+
+    property::assert_holds(
+        "four rotations are the identity",
+        0..50,
+        platforms,
+        |input| {
+            let platform = parse_platform(input).ok()?;
+            let rotated = (0..4).fold(platform.clone(), |platform, _| platform.rotate_clockwise());
+            Some(rotated == platform)
+        },
+    );
+
+    property::assert_holds(
+        "tilting twice is the same as once",
+        0..50,
+        platforms,
+        |input| {
+            let tilted = tilt_north(parse_platform(input).ok()?);
+            Some(tilt_north(tilted.clone()) == tilted)
+        },
+    );
+
+    property::assert_holds("spinning keeps every rock", 0..50, platforms, |input| {
+        let platform = parse_platform(input).ok()?;
+        let count =
+            |platform: &Platform, rock| platform.iter().filter(|(_, &tile)| tile == rock).count();
+        let spun = spin_cycle(&platform);
+        Some(
+            [ROUND_ROCK, CUBE_ROCK]
+                .iter()
+                .all(|&rock| count(&spun, rock) == count(&platform, rock)),
+        )
+    });
+}
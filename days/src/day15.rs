@@ -0,0 +1,257 @@
+use aoc::generate::{Options, Rng};
+use aoc::solution::{Explore, Print, Query};
+use aoc::{parse, ParseError, Result, Solution};
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(PartialEq)]
+pub struct Step {
+    pub text: String, // the step as written, which is what gets hashed
+    pub label: String,
+    pub focal_length: Option<usize>, // lens to insert, or none to remove the label's lens
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed = Vec<Step>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.trim().split(',').map(parse_step).collect()
+    }
+
+    fn part_1(sequence: &Self::Parsed) -> Result<usize> {
+        Ok(sequence.iter().map(|step| calculate_hash(&step.text)).sum())
+    }
+
+    fn part_2(sequence: &Self::Parsed) -> Result<usize> {
+        Ok(calculate_focusing_power(&arrange_lenses(sequence)))
+    }
+}
+
+impl Print for Day15 {
+    fn print(sequence: &Self::Parsed) -> String {
+        let steps: Vec<_> = sequence.iter().map(|step| step.to_string()).collect();
+        steps.join(",") + "\n"
+    }
+}
+
+impl Explore for Day15 {
+    const QUERIES: &'static [Query] = &[
+        Query {
+            name: "hash",
+            args: &["<text>"],
+            about: "the HASH of any text",
+        },
+        Query {
+            name: "box",
+            args: &["<number>"],
+            about: "the lenses left in the box once every step is done",
+        },
+        Query {
+            name: "lens",
+            args: &["<label>"],
+            about: "where the lens with the label ends up once every step is done",
+        },
+    ];
+
+    fn query(sequence: &Self::Parsed, query: &str, args: &[&str]) -> Result<String> {
+        let boxes = arrange_lenses(sequence);
+
+        match query {
+            "hash" => Ok(calculate_hash(args[0]).to_string()),
+            "box" => {
+                let box_number: usize = parse::number(args[0])?;
+
+                if box_number > 255 {
+                    let message = format!("expected a box from 0 to 255, found {box_number}");
+                    return Err(ParseError::new(args[0], message));
+                }
+
+                let lenses: Vec<_> = boxes
+                    .get(&box_number)
+                    .into_iter()
+                    .flatten()
+                    .map(|(label, focal_length)| format!("[{label} {focal_length}]"))
+                    .collect();
+
+                match lenses.is_empty() {
+                    true => Ok("empty".to_string()),
+                    false => Ok(lenses.join(" ")),
+                }
+            }
+            "lens" => {
+                let label = args[0];
+                let box_number = calculate_hash(label);
+                let slot = boxes
+                    .get(&box_number)
+                    .and_then(|lenses| lenses.iter().position(|lens| lens.0 == label));
+
+                match slot {
+                    Some(i) => {
+                        let focal_length = boxes[&box_number][i].1;
+                        let slot_number = i + 1;
+                        Ok(format!(
+                            "box {box_number}, slot {slot_number}, focal length {focal_length}"
+                        ))
+                    }
+                    None => Ok(format!("not in box {box_number}")),
+                }
+            }
+            _ => unreachable!("unknown query `{query}`"),
+        }
+    }
+}
+
+fn arrange_lenses(sequence: &[Step]) -> HashMap<usize, Vec<(&str, usize)>> {
+    let mut boxes: HashMap<usize, Vec<(&str, usize)>> = HashMap::new();
+
+    sequence.iter().for_each(|step| {
+        let label = step.label.as_str();
+        let focal_length = step.focal_length;
+        let box_number = calculate_hash(label);
+        let lenses = boxes.entry(box_number).or_default();
+
+        if let Some(focal_length) = focal_length {
+            if let Some(i) = lenses.iter().position(|lens| lens.0 == label) {
+                lenses[i] = (label, focal_length);
+            } else {
+                lenses.push((label, focal_length));
+            }
+        } else {
+            lenses.retain(|lens| lens.0 != label);
+        }
+    });
+
+    boxes
+}
+
+fn calculate_focusing_power(boxes: &HashMap<usize, Vec<(&str, usize)>>) -> usize {
+    let mut result = 0;
+
+    for (box_number, lenses) in boxes.iter() {
+        for (i, (_, focal_length)) in lenses.iter().enumerate() {
+            let slot_number = i + 1;
+            let lens_focusing_power = (1 + box_number) * slot_number * focal_length;
+            result += lens_focusing_power;
+        }
+    }
+
+    result
+}
+
+fn calculate_hash(text: &str) -> usize {
+    text.chars().fold(0, |mut hash, c| {
+        hash += c as usize;
+        hash *= 17;
+        hash %= 256;
+        hash
+    })
+}
+
+fn parse_step(step: &str) -> Result<Step> {
+    let (label, focal_length) = if let Some((label, focal_length_str)) = step.split_once('=') {
+        (label, Some(parse::number(focal_length_str)?))
+    } else {
+        (parse::strip_suffix(step, "-")?, None)
+    };
+
+    Ok(Step {
+        text: step.to_string(),
+        label: label.to_string(),
+        focal_length,
+    })
+}
+
+/// An initialization sequence that inserts and removes lenses with a limited set of labels, so the
+/// same labels keep coming up. The size is the number of steps.
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let labels: Vec<String> = (0..options.size / 4 + 1)
+        .map(|_| {
+            (0..rng.range(2..7))
+                .map(|_| char::from(b'a' + rng.range(0..26) as u8))
+                .collect()
+        })
+        .collect();
+
+    let steps: Vec<_> = (0..options.size.max(1))
+        .map(|_| {
+            let label = rng.choose(&labels);
+
+            match rng.chance(0.7) {
+                true => format!("{label}={}", rng.range(1..10)),
+                false => format!("{label}-"),
+            }
+        })
+        .collect();
+
+    steps.join(",") + "\n"
+}
+
+#[test]
+fn properties() {
+    use aoc::property;
+
+    // Any printable text, not just the steps in real inputs.
+    let texts = |rng: &mut Rng| {
+        let length = rng.range(0..40);
+        let text: String = (0..length)
+            .map(|_| rng.range(0x20..0x7f) as u8 as char)
+            .collect();
+        text + "\n"
+    };
+
+    property::assert_holds("hashes are below 256", 0..200, texts, |text| {
+        Some(calculate_hash(text.trim_end_matches('\n')) < 256)
+    });
+
+    // The HASH algorithm keeps its value in a byte, so it can wrap rather than taking remainders.
+    property::assert_holds("hashing wraps like a byte", 0..200, texts, |text| {
+        let text = text.trim_end_matches('\n');
+        let wrapped = text
+            .bytes()
+            .fold(0u8, |hash, byte| hash.wrapping_add(byte).wrapping_mul(17));
+
+        Some(calculate_hash(text) == wrapped as usize)
+    });
+}
+
+#[test]
+fn queries() {
+    let input = include_str!("../../examples/day15/sample.txt");
+    let session = aoc::solution::explore::<Day15>(input).unwrap();
+    let ask = |query, args: &[&str]| session.ask(query, args).map_err(|error| error.to_string());
+
+    assert_eq!(ask("hash", &["HASH"]), Ok("52".to_string()));
+    assert_eq!(ask("box", &["3"]), Ok("[ot 7] [ab 5] [pc 6]".to_string()));
+    assert_eq!(ask("box", &["1"]), Ok("empty".to_string()));
+    assert_eq!(
+        ask("lens", &["pc"]),
+        Ok("box 3, slot 3, focal length 6".to_string())
+    );
+    assert_eq!(ask("box", &[]), Err("usage: box <number>".to_string()));
+    assert_eq!(ask("boxes", &[]), Err("unknown query `boxes`".to_string()));
+}
+
+#[test]
+fn steps_hash_as_written() {
+    // Leading zeros and labels that aren't lowercase are still steps, hashed the way they were written.
+    let sequence = Day15::parse("ab=05,AB-,=0\n").unwrap();
+
+    assert_eq!(sequence[0].focal_length, Some(5));
+    assert_eq!(sequence[1].label, "AB");
+    assert_eq!(
+        Day15::part_1(&sequence).unwrap(),
+        calculate_hash("ab=05") + calculate_hash("AB-") + calculate_hash("=0")
+    );
+    assert_eq!(Day15::print(&sequence), "ab=05,AB-,=0\n");
+}
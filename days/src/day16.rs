@@ -0,0 +1,163 @@
+use aoc::generate::{Options, Rng};
+use aoc::grid::{self, Cell, Direction, Grid, DIR_E, DIR_N, DIR_S, DIR_W};
+use aoc::pool;
+use aoc::render::{Frames, Scene};
+use aoc::search::{self, Reached};
+use aoc::solution::{Animate, Print, Render};
+use aoc::{Result, Solution};
+use std::collections::HashSet;
+use std::iter;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Parsed = Grid;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        grid::parse_grid_of(input, r".|-/\")
+    }
+
+    fn part_1(grid: &Self::Parsed) -> Result<usize> {
+        Ok(count_energized_tiles(grid, DIR_E, (0, 0)))
+    }
+
+    fn part_2(grid: &Self::Parsed) -> Result<usize> {
+        Ok(find_max_energized_tiles(grid))
+    }
+}
+
+impl Print for Day16 {
+    fn print(grid: &Self::Parsed) -> String {
+        grid.to_string()
+    }
+}
+
+impl Render for Day16 {
+    fn render(grid: &Self::Parsed) -> Scene {
+        Scene::new(grid.clone()).with_layer("energized", find_energized_cells(grid, DIR_E, (0, 0)))
+    }
+}
+
+impl Animate for Day16 {
+    fn animate(grid: Self::Parsed) -> Frames {
+        // The beams a step at a time, lighting up tiles as they pass.
+        let beams = trace_beams(&grid, DIR_E, (0, 0));
+        let mut steps = Vec::new();
+
+        for (&(_, cell), step) in beams.iter() {
+            if steps.len() <= step {
+                steps.resize(step + 1, Vec::new());
+            }
+
+            steps[step].push(cell);
+        }
+
+        let frames = steps
+            .into_iter()
+            .scan(HashSet::new(), move |energized, beam| {
+                energized.extend(beam.iter().copied());
+
+                let scene = Scene::new(grid.clone())
+                    .with_layer("energized", energized.iter().copied())
+                    .with_layer("beam", beam);
+
+                Some(scene)
+            });
+
+        Box::new(frames)
+    }
+}
+
+fn find_max_energized_tiles(grid: &Grid) -> usize {
+    let row_count = grid.row_count();
+    let col_count = grid.col_count();
+
+    let starts: Vec<_> = iter::empty()
+        .chain((0..col_count).map(|col| (DIR_N, (row_count - 1, col))))
+        .chain((0..col_count).map(|col| (DIR_S, (0, col))))
+        .chain((0..row_count).map(|row| (DIR_E, (row, 0))))
+        .chain((0..row_count).map(|row| (DIR_W, (row, col_count - 1))))
+        .collect();
+
+    // Each start's beam is traced on its own, so they can be traced side by side.
+    pool::map(&starts, |&(start_direction, start_cell)| {
+        count_energized_tiles(grid, start_direction, start_cell)
+    })
+    .into_iter()
+    .max()
+    .unwrap()
+}
+
+fn count_energized_tiles(grid: &Grid, start_direction: Direction, start_cell: Cell) -> usize {
+    find_energized_cells(grid, start_direction, start_cell).len()
+}
+
+fn find_energized_cells(
+    grid: &Grid,
+    start_direction: Direction,
+    start_cell: Cell,
+) -> HashSet<Cell> {
+    let beams = trace_beams(grid, start_direction, start_cell);
+    beams.iter().map(|(&(_, cell), _)| cell).collect()
+}
+
+/// Every direction a beam passes through every cell in, and how many steps it takes to get there.
+fn trace_beams(
+    grid: &Grid,
+    start_direction: Direction,
+    start_cell: Cell,
+) -> Reached<(Direction, Cell)> {
+    search::reachable((start_direction, start_cell), |&(direction, cell)| {
+        get_next_directions(grid, direction, cell)
+            .into_iter()
+            .filter_map(move |next_direction| {
+                let next_cell = grid.adjacent_cell(cell, next_direction)?;
+                Some((next_direction, next_cell))
+            })
+    })
+}
+
+fn get_next_directions(grid: &Grid, direction: Direction, cell: Cell) -> Vec<Direction> {
+    match (grid[cell], direction) {
+        ('-', DIR_N | DIR_S) => vec![DIR_E, DIR_W],
+        ('|', DIR_E | DIR_W) => vec![DIR_N, DIR_S],
+        ('/', DIR_N) => vec![DIR_E],
+        ('/', DIR_S) => vec![DIR_W],
+        ('/', DIR_E) => vec![DIR_N],
+        ('/', DIR_W) => vec![DIR_S],
+        ('\\', DIR_N) => vec![DIR_W],
+        ('\\', DIR_S) => vec![DIR_E],
+        ('\\', DIR_E) => vec![DIR_S],
+        ('\\', DIR_W) => vec![DIR_N],
+        _ => vec![direction],
+    }
+}
+
+/// A square contraption of mirrors and splitters. The size is the length of each side, and the
+/// density is the chance of each tile holding one (by default 0.1).
+pub fn generate(rng: &mut Rng, options: &Options) -> String {
+    let side = options.size.max(1);
+    let density = options.density_or(0.1);
+
+    let grid = Grid::filled(side, side, ()).map(|_| match rng.chance(density) {
+        true => *rng.choose(&['|', '-', '/', '\\']),
+        false => '.',
+    });
+
+    grid.to_string()
+}
+
+#[test]
+fn animation() {
+    let input = include_str!("../../examples/day16/sample.txt");
+    let grid = Day16::parse(input).unwrap();
+    let frames: Vec<_> = Day16::animate(grid.clone()).collect();
+
+    assert_eq!(frames[0].layers[1].cells, vec![(0, 0)]);
+
+    let energized = &frames[frames.len() - 1].layers[0].cells;
+    assert_eq!(energized.len(), Day16::part_1(&grid).unwrap());
+}
@@ -0,0 +1,26 @@
+//! Tests generated from the example inputs in `examples/dayNN/`, one per example and part listed in
+//! `examples/answers.txt`, and solved a line at a time too for days that support streaming:
+//!
+//! ```text
+//! # file part answer
+//! day14/sample.txt 2 64
+//! ```
+
+macro_rules! example_test {
+    ($name:ident, $day:literal, $part:literal, $file:literal, $answer:literal) => {
+        #[test]
+        fn $name() {
+            let input = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../examples/", $file));
+            let day = crate::find_day($day).expect("the day should be registered");
+            let report = (day.run)(input, &[$part]).unwrap_or_else(|error| panic!("{error}"));
+            assert_eq!(report.parts[0].answer, $answer);
+
+            if let Some(stream) = day.stream {
+                let report = stream(&mut input.as_bytes(), &[$part]).unwrap();
+                assert_eq!(report.parts[0].answer, $answer, "when streamed");
+            }
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
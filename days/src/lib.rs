@@ -0,0 +1,194 @@
+//! Solutions for Advent of Code 2023, one module per day.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+#[cfg(test)]
+mod examples;
+
+use aoc::generate::{Options, Rng};
+use aoc::render::{Frames, Scene};
+use aoc::solution::{self, Report, Session};
+use aoc::Solution;
+use std::io::BufRead;
+use std::ops::Range;
+
+pub const YEAR: u16 = 2023;
+
+/// A registered puzzle, with its solution erased to a function so every day can be run uniformly.
+pub struct Day {
+    pub number: u8,
+    pub run: fn(&str, &[u8]) -> aoc::Result<Report>,
+    pub stream: Option<Stream>, // only for days that can be solved a line at a time
+    pub generate: fn(&mut Rng, &Options) -> String,
+    pub oracle: Option<Oracle>, // only for days with a reference implementation kept around
+    pub render: Option<Draw>,   // only for grid puzzles worth looking at
+    pub animate: Option<Animation>, // only for puzzles that play out step by step
+    pub explore: Option<Explorer>, // only for puzzles worth asking about in the REPL
+}
+
+/// Solves the given parts from an input read a line at a time. See [`solution::run_stream`].
+pub type Stream = fn(&mut dyn BufRead, &[u8]) -> aoc::Result<Report>;
+
+/// Checks a day's solution against its slower reference implementation on inputs generated from
+/// each seed, reporting the first input they disagree on. See [`aoc::oracle::cross_check`].
+pub type Oracle = fn(Range<u64>, &Options) -> Result<(), String>;
+
+/// Parses the input and draws it, with what the day works out highlighted. See
+/// [`solution::render`].
+pub type Draw = fn(&str) -> aoc::Result<Scene>;
+
+/// Parses the input and plays it out a frame at a time. See [`solution::animate`].
+pub type Animation = fn(&str) -> aoc::Result<Frames>;
+
+/// Parses the input and keeps it to be asked about. See [`solution::explore`].
+pub type Explorer = fn(&str) -> aoc::Result<Session>;
+
+macro_rules! day {
+    ($module:ident::$solution:ident $(, $feature:ident)*) => {
+        Day {
+            $($feature: day!(@$feature $module::$solution),)*
+            ..Day {
+                number: $module::$solution::DAY,
+                run: solution::run::<$module::$solution>,
+                stream: None,
+                generate: $module::generate,
+                oracle: None,
+                render: None,
+                animate: None,
+                explore: None,
+            }
+        }
+    };
+    (@stream $module:ident::$solution:ident) => {
+        Some(solution::run_stream::<$module::$solution>)
+    };
+    (@oracle $module:ident::$solution:ident) => {
+        Some($module::cross_check)
+    };
+    (@render $module:ident::$solution:ident) => {
+        Some(solution::render::<$module::$solution>)
+    };
+    (@animate $module:ident::$solution:ident) => {
+        Some(solution::animate::<$module::$solution>)
+    };
+    (@explore $module:ident::$solution:ident) => {
+        Some(solution::explore::<$module::$solution>)
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(day01::Day01, stream),
+    day!(day02::Day02, stream),
+    day!(day03::Day03),
+    day!(day04::Day04, stream),
+    day!(day05::Day05, oracle, explore),
+    day!(day06::Day06, oracle),
+    day!(day07::Day07, stream),
+    day!(day08::Day08, explore),
+    day!(day09::Day09, stream),
+    day!(day10::Day10, render),
+    day!(day11::Day11, render),
+    day!(day12::Day12, stream, oracle),
+    day!(day13::Day13),
+    day!(day14::Day14, render, animate),
+    day!(day15::Day15, explore),
+    day!(day16::Day16, render, animate),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[test]
+fn generated_inputs() {
+    for day in DAYS {
+        for seed in 0..3 {
+            let options = Options::new(8);
+            let input = (day.generate)(&mut Rng::new(seed), &options);
+            assert_eq!(input, (day.generate)(&mut Rng::new(seed), &options));
+
+            let number = day.number;
+            let report = (day.run)(&input, &[1, 2])
+                .unwrap_or_else(|error| panic!("seed {seed}: {error}\n{input}"));
+
+            if let Some(stream) = day.stream {
+                let streamed = stream(&mut input.as_bytes(), &[1, 2]).unwrap();
+
+                for (part, streamed) in report.parts.iter().zip(streamed.parts) {
+                    assert_eq!(part.answer, streamed.answer, "day {number} seed {seed}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_print_round_trips() {
+    use aoc::property;
+    use aoc::solution::Print;
+
+    // Printing what was parsed has to parse back to the same, and print the same again.
+    fn round_trips<S: Print>(input: &str) -> Option<bool>
+    where
+        S::Parsed: PartialEq,
+    {
+        let parsed = S::parse(input).ok()?;
+        let printed = S::print(&parsed);
+
+        let Ok(reparsed) = S::parse(&printed) else {
+            return Some(false);
+        };
+
+        Some(reparsed == parsed && S::print(&reparsed) == printed)
+    }
+
+    macro_rules! assert_round_trips {
+        ($($module:ident::$solution:ident),*) => {
+            $(
+                property::assert_holds(
+                    concat!(stringify!($module), " prints what it parsed"),
+                    0..20,
+                    |rng| {
+                        let size = rng.range(1..12);
+                        $module::generate(rng, &Options::new(size))
+                    },
+                    round_trips::<$module::$solution>,
+                );
+            )*
+        };
+    }
+
+    assert_round_trips!(
+        day01::Day01,
+        day02::Day02,
+        day03::Day03,
+        day04::Day04,
+        day05::Day05,
+        day06::Day06,
+        day07::Day07,
+        day08::Day08,
+        day09::Day09,
+        day10::Day10,
+        day11::Day11,
+        day12::Day12,
+        day13::Day13,
+        day14::Day14,
+        day15::Day15,
+        day16::Day16
+    );
+}